@@ -0,0 +1,71 @@
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An ordered map that serializes to a Garnish list of associations instead of a list of lists.
+///
+/// Entries are kept in insertion order, unless reordered by [`crate::MapKeyOrdering`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GarnishAssocList<V>(pub Vec<(String, V)>);
+
+impl<V> From<Vec<(String, V)>> for GarnishAssocList<V> {
+    fn from(value: Vec<(String, V)>) -> Self {
+        GarnishAssocList(value)
+    }
+}
+
+impl<V> Serialize for GarnishAssocList<V>
+where
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut m = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in self.0.iter() {
+            m.serialize_entry(key, value)?;
+        }
+        m.end()
+    }
+}
+
+struct GarnishAssocListVisitor<V>(PhantomData<V>);
+
+impl<'de, V> Visitor<'de> for GarnishAssocListVisitor<V>
+where
+    V: Deserialize<'de>,
+{
+    type Value = GarnishAssocList<V>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of associations")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(GarnishAssocList(entries))
+    }
+}
+
+impl<'de, V> Deserialize<'de> for GarnishAssocList<V>
+where
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(GarnishAssocListVisitor(PhantomData))
+    }
+}
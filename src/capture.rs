@@ -0,0 +1,240 @@
+//! Serializer that reads simple values without adding anything to data,
+//! used when the value decides what [`crate::GarnishDataSerializer`] adds.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use serde::ser::Impossible;
+use serde::{Serialize, Serializer};
+
+use crate::optional::GARNISH_NONE_SYMBOL_NAME;
+use crate::{GarnishSerializationOptions, OptionalBehavior, StructBehavior};
+
+/// Value read by [`LeafCapture`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Leaf {
+    Unit,
+    Integer(i128),
+    Float(f64),
+}
+
+/// Value wasn't one [`LeafCapture`] reads.
+#[derive(Debug)]
+pub(crate) struct NotLeaf;
+
+impl Display for NotLeaf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("value is not a leaf")
+    }
+}
+
+impl Error for NotLeaf {}
+
+impl serde::ser::Error for NotLeaf {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: Display,
+    {
+        NotLeaf
+    }
+}
+
+/// Reads values the way [`crate::GarnishDataSerializer`] would store them with the same options.
+pub(crate) struct LeafCapture<'o> {
+    options: &'o GarnishSerializationOptions,
+}
+
+impl<'o> LeafCapture<'o> {
+    pub(crate) fn capture<T>(value: &T, options: &'o GarnishSerializationOptions) -> Option<Leaf>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(LeafCapture { options }).ok()
+    }
+}
+
+impl<'o> Serializer for LeafCapture<'o> {
+    type Ok = Leaf;
+    type Error = NotLeaf;
+    type SerializeSeq = Impossible<Leaf, NotLeaf>;
+    type SerializeTuple = Impossible<Leaf, NotLeaf>;
+    type SerializeTupleStruct = Impossible<Leaf, NotLeaf>;
+    type SerializeTupleVariant = Impossible<Leaf, NotLeaf>;
+    type SerializeMap = Impossible<Leaf, NotLeaf>;
+    type SerializeStruct = Impossible<Leaf, NotLeaf>;
+    type SerializeStructVariant = Impossible<Leaf, NotLeaf>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Integer(v.into()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        i128::try_from(v).map(Leaf::Integer).or(Err(NotLeaf))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Float(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Float(v))
+    }
+
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        match self.options.optional_behavior {
+            OptionalBehavior::UnitValue => Ok(Leaf::Unit),
+            OptionalBehavior::UnitSymbol => Err(NotLeaf),
+        }
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self.options.preserve_option_nesting {
+            true => Err(NotLeaf),
+            false => value.serialize(self),
+        }
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Leaf::Unit)
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        match self.options.struct_typing_behavior {
+            StructBehavior::ExcludeTyping if name != GARNISH_NONE_SYMBOL_NAME => Ok(Leaf::Unit),
+            _ => Err(NotLeaf),
+        }
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        // serialized as their value by GarnishDataSerializer
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(NotLeaf)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(NotLeaf)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(NotLeaf)
+    }
+}
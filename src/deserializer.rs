@@ -1,15 +1,53 @@
 use std::convert::From;
+use std::marker::PhantomData;
 
-use serde::de::value::StrDeserializer;
+use serde::de::value::{I64Deserializer, SeqDeserializer, StrDeserializer, U32Deserializer};
 use serde::de::{
     DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
 };
-use serde::Deserializer;
+use serde::{Deserialize, Deserializer};
 
 use garnish_lang_traits::{GarnishData, GarnishDataType, TypeConstants};
 
 use crate::error::{wrap_err, GarnishSerializationError};
-use crate::GarnishNumberConversions;
+use crate::optional::GARNISH_NONE_SYMBOL_NAME;
+use crate::traits::Number128;
+use crate::{
+    GarnishBorrowedData, GarnishDeserializationOptions, GarnishNumber128Conversions,
+    GarnishNumberConversions, GarnishStringConversions, OptionalBehavior, SymbolStringBehavior,
+    TagPosition, UnknownVariantBehavior,
+};
+
+// 2^53, largest integer every JSON implementation can represent exactly
+const JSON_MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+type StringConversion<Data> =
+    fn(&Data, <Data as GarnishData>::Size) -> Result<String, <Data as GarnishData>::Error>;
+
+type StrAccess<Data> = fn(&Data, <Data as GarnishData>::Size) -> Option<&str>;
+
+type BytesAccess<Data> = fn(&Data, <Data as GarnishData>::Size) -> Option<&[u8]>;
+
+enum DataAccess<'data, Data>
+where
+    Data: GarnishData,
+{
+    // strings are created by adding a char list to data
+    Mutable(&'data mut Data),
+    // strings are created through GarnishStringConversions
+    // char and byte lists are borrowed through GarnishBorrowedData when available
+    ReadOnly(
+        &'data Data,
+        StringConversion<Data>,
+        Option<(StrAccess<Data>, BytesAccess<Data>)>,
+    ),
+}
+
+#[derive(Clone, Copy)]
+enum PathSegment<Size> {
+    Index(usize),
+    Key(Size),
+}
 
 pub struct GarnishDataDeserializer<'data, Data>
 where
@@ -19,8 +57,14 @@ where
     Data::Char: Into<char>,
     Data::Byte: Into<u8>,
 {
-    data: &'data mut Data,
+    data: DataAccess<'data, Data>,
     value_stack: Vec<Data::Size>,
+    options: GarnishDeserializationOptions,
+    string_buffer: String,
+    // keys and indices leading to the current value, resolved to a string only on error
+    path: Vec<PathSegment<Data::Size>>,
+    // set when numbers hold integers beyond 64 bits
+    number_128: Option<Number128<Data::Number>>,
 }
 
 impl<'data, Data> GarnishDataDeserializer<'data, Data>
@@ -34,15 +78,108 @@ where
     pub fn new(data: &'data mut Data) -> Self {
         let v = data.get_current_value().unwrap_or(Data::Size::zero());
         Self {
-            data,
+            data: DataAccess::Mutable(data),
             value_stack: vec![v],
+            options: GarnishDeserializationOptions::new(),
+            string_buffer: String::new(),
+            path: vec![],
+            number_128: None,
         }
     }
 
     pub fn new_for_value(data: &'data mut Data, value_addr: Data::Size) -> Self {
         Self {
-            data,
+            data: DataAccess::Mutable(data),
+            value_stack: vec![value_addr],
+            options: GarnishDeserializationOptions::new(),
+            string_buffer: String::new(),
+            path: vec![],
+            number_128: None,
+        }
+    }
+
+    pub fn new_with_options(data: &'data mut Data, options: GarnishDeserializationOptions) -> Self {
+        Self {
+            options,
+            ..Self::new(data)
+        }
+    }
+
+    pub fn new_for_value_with_options(
+        data: &'data mut Data,
+        value_addr: Data::Size,
+        options: GarnishDeserializationOptions,
+    ) -> Self {
+        Self {
+            options,
+            ..Self::new_for_value(data, value_addr)
+        }
+    }
+
+    /// Create a deserializer that only reads from data.
+    /// Conversions to strings are made with [`GarnishStringConversions`] instead of adding a char list to data.
+    pub fn new_read_only(data: &'data Data) -> Self
+    where
+        Data: GarnishStringConversions,
+    {
+        let v = data.get_current_value().unwrap_or(Data::Size::zero());
+        Self::new_read_only_for_value(data, v)
+    }
+
+    pub fn new_read_only_for_value(data: &'data Data, value_addr: Data::Size) -> Self
+    where
+        Data: GarnishStringConversions,
+    {
+        Self {
+            data: DataAccess::ReadOnly(data, Data::create_string, None),
             value_stack: vec![value_addr],
+            options: GarnishDeserializationOptions::new(),
+            string_buffer: String::new(),
+            path: vec![],
+            number_128: None,
+        }
+    }
+
+    /// Create a deserializer that only reads from data and can deserialize `&str`
+    /// borrowed from char lists with [`GarnishBorrowedData`].
+    pub fn new_borrowing(data: &'data Data) -> Self
+    where
+        Data: GarnishBorrowedData,
+    {
+        let v = data.get_current_value().unwrap_or(Data::Size::zero());
+        Self::new_borrowing_for_value(data, v)
+    }
+
+    pub fn new_borrowing_for_value(data: &'data Data, value_addr: Data::Size) -> Self
+    where
+        Data: GarnishBorrowedData,
+    {
+        Self {
+            data: DataAccess::ReadOnly(
+                data,
+                Data::create_string,
+                Some((Data::get_char_list_str, Data::get_byte_list_slice)),
+            ),
+            ..Self::new_read_only_for_value(data, value_addr)
+        }
+    }
+
+    /// Deserialize `i128` and `u128` values beyond 64 bits, for data whose numbers can hold them.
+    /// Without this those values are limited to the range of `i64` and `u64`.
+    pub fn with_128_bit_numbers(self) -> Self
+    where
+        Data::Number: GarnishNumber128Conversions,
+    {
+        Self {
+            number_128: Some(Number128::new()),
+            ..self
+        }
+    }
+
+    fn data(&self) -> &Data {
+        match &self.data {
+            DataAccess::Mutable(data) => data,
+            DataAccess::ReadOnly(data, _, _) => data,
         }
     }
 
@@ -52,35 +189,200 @@ where
             .last()
             .ok_or(GarnishSerializationError::from("No value to deserialize."))?;
         let t = self
-            .data
+            .data()
             .get_data_type(a)
             .or_else(|e| Err(GarnishSerializationError::new(e)))?;
 
         Ok((t, a))
     }
 
-    fn create_symbol_string(
+    /// Address of the value the next deserialization will read.
+    pub fn current_addr(&self) -> Option<Data::Size> {
+        self.value_stack.last().copied()
+    }
+
+    /// Type of the value the next deserialization will read, without deserializing it.
+    pub fn current_type(&self) -> Result<GarnishDataType, GarnishSerializationError<Data>> {
+        self.value().map(|(t, _)| t)
+    }
+
+    /// Replace the current value, so the next deserialization reads the value at the given address.
+    pub fn set_current(&mut self, addr: Data::Size) -> Result<(), GarnishSerializationError<Data>> {
+        let current = self
+            .value_stack
+            .last_mut()
+            .ok_or(GarnishSerializationError::from("No value to reposition."))?;
+        *current = addr;
+
+        Ok(())
+    }
+
+    // nested values are pushed through here so deeply nested data fails before overflowing the stack
+    fn push_value(&mut self, addr: Data::Size) -> Result<(), GarnishSerializationError<Data>> {
+        if self.value_stack.len() >= self.options.max_depth {
+            return Err(GarnishSerializationError::from(
+                format!(
+                    "Maximum nesting depth of {} exceeded.",
+                    self.options.max_depth
+                )
+                .as_str(),
+            ));
+        }
+
+        self.value_stack.push(addr);
+        Ok(())
+    }
+
+    fn create_string(&mut self, a: Data::Size) -> Result<String, GarnishSerializationError<Data>> {
+        let mut s = String::new();
+        self.write_string(a, &mut s)?;
+        Ok(s)
+    }
+
+    fn write_string(
         &mut self,
         a: Data::Size,
-    ) -> Result<String, GarnishSerializationError<Data>> {
+        s: &mut String,
+    ) -> Result<(), GarnishSerializationError<Data>> {
         // for deserializing identifiers and enums we need to convert symbols to strings
-        // may need Garnish Data trait to have a method for direct to string conversion
-        let a = self.data.add_char_list_from(a).or_else(wrap_err)?;
+        let a = match &mut self.data {
+            DataAccess::Mutable(data) => match data.get_data_type(a).or_else(wrap_err)? {
+                // already a string, read it directly instead of adding a copy
+                GarnishDataType::CharList => a,
+                _ => data.add_char_list_from(a).or_else(wrap_err)?,
+            },
+            DataAccess::ReadOnly(data, create_string, _) => {
+                s.push_str(create_string(data, a).or_else(wrap_err)?.as_str());
+                return Ok(());
+            }
+        };
 
-        let len = self.data.get_char_list_len(a).or_else(wrap_err)?;
-        let mut s = String::with_capacity(len.into());
+        let len = self.data().get_char_list_len(a).or_else(wrap_err)?;
+        s.reserve(len.into());
         let mut i = Data::Size::zero();
 
         while i < len {
             let c = self
-                .data
+                .data()
                 .get_char_list_item(a, Data::size_to_number(i))
                 .or_else(wrap_err)?;
             s.push(c.into());
             i += Data::Size::one();
         }
 
-        Ok(s)
+        Ok(())
+    }
+
+    // convert value to a string and pass to given function
+    // using string_buffer when GarnishDeserializationOptions::reuse_string_buffer is enabled
+    fn with_string<F, R>(
+        &mut self,
+        a: Data::Size,
+        f: F,
+    ) -> Result<R, GarnishSerializationError<Data>>
+    where
+        F: FnOnce(&str) -> Result<R, GarnishSerializationError<Data>>,
+    {
+        if !self.options.reuse_string_buffer {
+            return f(self.create_string(a)?.as_str());
+        }
+
+        // take buffer so a nested use won't see the same one
+        let mut buffer = std::mem::take(&mut self.string_buffer);
+        buffer.clear();
+
+        let r = self.write_string(a, &mut buffer).and_then(|_| f(&buffer));
+        self.string_buffer = buffer;
+
+        r
+    }
+
+    // get tag and value addresses from a list, respecting GarnishDeserializationOptions::variant_tag_position
+    fn tagged_list_parts(
+        &self,
+        list: Data::Size,
+    ) -> Result<(Data::Size, Data::Size), GarnishSerializationError<Data>> {
+        let (tag_index, value_index) = match self.options.variant_tag_position {
+            TagPosition::First => (Data::Number::zero(), Data::Number::one()),
+            TagPosition::Last => {
+                let two = Data::Size::one() + Data::Size::one();
+                let len = self.data().get_list_len(list).or_else(wrap_err)?;
+                if len < two {
+                    return Err(GarnishSerializationError::from(
+                        "Expected tagged list to contain a value and a tag.",
+                    ));
+                }
+                // only the element before the tag is read, more would be silently dropped
+                if len > two {
+                    let len: usize = len.into();
+                    return Err(GarnishSerializationError::from(
+                        format!(
+                            "Expected tagged list with the tag last to contain only a value and a tag, found length {}",
+                            len
+                        )
+                        .as_str(),
+                    ));
+                }
+                (
+                    Data::size_to_number(len - Data::Size::one()),
+                    Data::size_to_number(len - two),
+                )
+            }
+        };
+
+        Ok((
+            self.data()
+                .get_list_item(list, tag_index)
+                .or_else(wrap_err)?,
+            self.data()
+                .get_list_item(list, value_index)
+                .or_else(wrap_err)?,
+        ))
+    }
+
+    // value of a Some wrapper created with GarnishSerializationOptions::preserve_option_nesting
+    fn option_wrapper_value(
+        &self,
+        list: Data::Size,
+    ) -> Result<Option<Data::Size>, GarnishSerializationError<Data>> {
+        let len = self.data().get_list_len(list).or_else(wrap_err)?;
+        if len != Data::Size::one() + Data::Size::one() {
+            return Ok(None);
+        }
+
+        let (tag, value) = self.tagged_list_parts(list)?;
+        if self.data().get_data_type(tag).or_else(wrap_err)? != GarnishDataType::Symbol {
+            return Ok(None);
+        }
+
+        let some = Data::parse_symbol("Some").or_else(wrap_err)?;
+        match self.data().get_symbol(tag).or_else(wrap_err)? == some {
+            true => Ok(Some(value)),
+            false => Ok(None),
+        }
+    }
+
+    fn with_path(&mut self, e: GarnishSerializationError<Data>) -> GarnishSerializationError<Data> {
+        if self.path.is_empty() {
+            return e;
+        }
+
+        e.with_path(|| {
+            let mut path = String::new();
+            for segment in self.path.clone() {
+                match segment {
+                    PathSegment::Index(i) => path.push_str(format!("[{}]", i).as_str()),
+                    PathSegment::Key(key) => {
+                        if !path.is_empty() {
+                            path.push('.');
+                        }
+                        let name = self.create_string(key).unwrap_or_else(|_| "?".to_string());
+                        path.push_str(name.as_str());
+                    }
+                }
+            }
+            path
+        })
     }
 
     fn deserialize_primitive<'de, From, To, V, GetF, VisitF>(
@@ -99,206 +401,175 @@ where
         let (t, a) = self.value()?;
         match t == expected_type {
             true => {
-                let v = get_source(self.data, a).or_else(wrap_err)?;
+                let v = get_source(self.data(), a).or_else(wrap_err)?;
                 visit_func(visitor, v.into())
             }
-            false => Err(GarnishSerializationError::from(
-                format!("Expected {:?}, found {:?}", expected_type, t).as_str(),
+            false => Err(GarnishSerializationError::type_mismatch(
+                &[expected_type],
+                t,
             )),
         }
     }
-}
-
-impl<'data, 'a, Data> Deserializer<'data> for &'a mut GarnishDataDeserializer<'data, Data>
-where
-    Data: GarnishData,
-    Data::Number: GarnishNumberConversions,
-    Data::Size: From<usize>,
-    Data::Size: Into<usize>,
-    Data::Char: From<char>,
-    Data::Char: Into<char>,
-    Data::Byte: From<u8>,
-    Data::Byte: Into<u8>,
-{
-    type Error = GarnishSerializationError<Data>;
-
-    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        unimplemented!()
-    }
-
-    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        let (t, _a) = self.value()?;
 
+    // Type values read as the id of the type they hold
+    // so #[repr] enums can be stored as Garnish types
+    fn type_id(&self) -> Result<Option<u8>, GarnishSerializationError<Data>> {
+        let (t, a) = self.value()?;
         match t {
-            GarnishDataType::True => visitor.visit_bool(true),
-            GarnishDataType::False => visitor.visit_bool(false),
-            t => Err(GarnishSerializationError::from(
-                format!("Expected True or False, found {:?}", t).as_str(),
-            )),
+            GarnishDataType::Type => Ok(Some(self.data().get_type(a).or_else(wrap_err)? as u8)),
+            _ => Ok(None),
         }
     }
 
-    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    // check number fits in the requested integer type
+    // so the conversion provided by data doesn't silently wrap or truncate
+    fn deserialize_integer<'de, To, V, VisitF>(
+        &self,
+        visitor: V,
+        min: f64,
+        max: f64,
+        visit_func: VisitF,
+    ) -> Result<V::Value, GarnishSerializationError<Data>>
     where
-        V: Visitor<'data>,
+        V: Visitor<'de>,
+        Data::Number: Into<To>,
+        VisitF: FnOnce(V, To) -> Result<V::Value, GarnishSerializationError<Data>>,
     {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_i8,
-            GarnishDataType::Number,
-        )
+        self.deserialize_integer_with(visitor, min, max, Into::into, visit_func)
     }
 
-    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_integer_with<'de, To, V, VisitF>(
+        &self,
+        visitor: V,
+        min: f64,
+        max: f64,
+        convert: fn(Data::Number) -> To,
+        visit_func: VisitF,
+    ) -> Result<V::Value, GarnishSerializationError<Data>>
     where
-        V: Visitor<'data>,
+        V: Visitor<'de>,
+        VisitF: FnOnce(V, To) -> Result<V::Value, GarnishSerializationError<Data>>,
     {
+        let json_compat = self.options.json_compat;
+        let lenient = self.options.lenient_number_coercion;
         self.deserialize_primitive(
             visitor,
             Data::get_number,
-            V::visit_i16,
-            GarnishDataType::Number,
-        )
-    }
+            |visitor, n: Data::Number| {
+                let mut f: f64 = n.into();
+                let mut n = n;
+                if lenient && f.fract() != 0.0 {
+                    f = f.trunc();
+                    n = Data::Number::from(f);
+                }
 
-    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_i32,
-            GarnishDataType::Number,
-        )
-    }
+                if json_compat && f.abs() > JSON_MAX_EXACT_INTEGER {
+                    return Err(GarnishSerializationError::from(
+                        format!("Number {} exceeds the 2^53 precision of JSON numbers", n).as_str(),
+                    ));
+                }
 
-    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_i64,
-            GarnishDataType::Number,
-        )
-    }
+                if f.fract() != 0.0 || f < min || f > max {
+                    return Err(GarnishSerializationError::from(
+                        format!(
+                            "Number {} is out of range for {}",
+                            n,
+                            std::any::type_name::<To>()
+                        )
+                        .as_str(),
+                    ));
+                }
 
-    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_u8,
+                visit_func(visitor, convert(n))
+            },
             GarnishDataType::Number,
         )
     }
+}
 
-    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+impl<'data, Data> GarnishDataDeserializer<'data, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    /// Deserialize each of the given addresses in turn, reusing this deserializer.
+    /// The value stack and path are reset before each value.
+    pub fn values_iter<T, I>(
+        &mut self,
+        addrs: I,
+    ) -> GarnishValuesIter<'_, 'data, Data, T, I::IntoIter>
     where
-        V: Visitor<'data>,
+        T: Deserialize<'data>,
+        I: IntoIterator<Item = Data::Size>,
     {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_u16,
-            GarnishDataType::Number,
-        )
+        GarnishValuesIter {
+            de: self,
+            addrs: addrs.into_iter(),
+            value: PhantomData,
+        }
     }
 
-    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    /// Deserialize the value at the given address, leaving the current value unchanged afterwards,
+    /// even when deserialization fails.
+    pub fn deserialize_at<T>(
+        &mut self,
+        addr: Data::Size,
+    ) -> Result<T, GarnishSerializationError<Data>>
     where
-        V: Visitor<'data>,
+        T: Deserialize<'data>,
     {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_u32,
-            GarnishDataType::Number,
-        )
+        let depth = self.value_stack.len();
+        let path_len = self.path.len();
+        self.push_value(addr)?;
+
+        let r = T::deserialize(&mut *self);
+        self.value_stack.truncate(depth);
+        self.path.truncate(path_len);
+
+        r
     }
 
-    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    // bytes aren't stored at their own addresses, so they can't be read through ListAccessor
+    fn deserialize_byte_list_seq<V>(
+        &mut self,
+        a: Data::Size,
+        visitor: V,
+    ) -> Result<V::Value, GarnishSerializationError<Data>>
     where
         V: Visitor<'data>,
     {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_u64,
-            GarnishDataType::Number,
-        )
+        let mut seq: SeqDeserializer<_, GarnishSerializationError<Data>> =
+            SeqDeserializer::new(gather_bytes(a, self.data())?.into_iter());
+        let value = visitor.visit_seq(&mut seq)?;
+        seq.end()?;
+
+        Ok(value)
     }
 
-    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_f32,
-            GarnishDataType::Number,
-        )
-    }
-
-    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_number,
-            V::visit_f64,
-            GarnishDataType::Number,
-        )
-    }
-
-    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        self.deserialize_primitive(
-            visitor,
-            Data::get_char,
-            V::visit_char,
-            GarnishDataType::Char,
-        )
-    }
-
-    fn deserialize_str<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        Err(GarnishSerializationError::from(
-            "Deserialization of &str not supported, use owned type String instead.",
-        ))
-    }
-
-    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_string_like<V>(
+        &mut self,
+        visitor: V,
+        allow_symbol: bool,
+    ) -> Result<V::Value, GarnishSerializationError<Data>>
     where
         V: Visitor<'data>,
     {
         let (t, a) = self.value()?;
         match t {
             GarnishDataType::CharList => {
-                let len = self.data.get_char_list_len(a).or_else(wrap_err)?;
+                let len = self.data().get_char_list_len(a).or_else(wrap_err)?;
                 let mut s = String::with_capacity(len.into());
                 let mut i = Data::Size::zero();
 
                 while i < len {
                     let c = self
-                        .data
+                        .data()
                         .get_char_list_item(a, Data::size_to_number(i))
                         .or_else(wrap_err)?;
                     s.push(c.into());
@@ -307,183 +578,777 @@ where
 
                 visitor.visit_string(s)
             }
+            GarnishDataType::ByteList if self.options.byte_list_as_string => {
+                let s = String::from_utf8(gather_bytes(a, self.data())?).map_err(|e| {
+                    GarnishSerializationError::from(
+                        format!("ByteList is not valid UTF-8: {}", e).as_str(),
+                    )
+                })?;
+                visitor.visit_string(s)
+            }
             // in terms of converting to Rust types, symbols can be treated as Strings if requested
+            GarnishDataType::Symbol if !allow_symbol => {
+                Err(GarnishSerializationError::type_mismatch(
+                    &[
+                        GarnishDataType::CharList,
+                        GarnishDataType::Concatenation,
+                        GarnishDataType::Slice,
+                    ],
+                    GarnishDataType::Symbol,
+                ))
+            }
             GarnishDataType::Symbol | GarnishDataType::Concatenation | GarnishDataType::Slice => {
-                // need to create a CharList first
-                // may need Garnish Data trait to have a method for direct to string conversion
-                let a = self.data.add_char_list_from(a).or_else(wrap_err)?;
-                visitor.visit_string(self.create_symbol_string(a)?)
+                match self.options.reuse_string_buffer {
+                    true => self.with_string(a, |s| visitor.visit_str(s)),
+                    false => visitor.visit_string(self.create_string(a)?),
+                }
             }
-            t => Err(GarnishSerializationError::from(
-                format!(
-                    "Expected CharList, Symbol, Concatenation or Slice. Found {:?}",
-                    t
-                )
-                .as_str(),
+            t => Err(GarnishSerializationError::type_mismatch(
+                &[
+                    GarnishDataType::CharList,
+                    GarnishDataType::Symbol,
+                    GarnishDataType::Concatenation,
+                    GarnishDataType::Slice,
+                ],
+                t,
             )),
         }
     }
+}
 
-    fn deserialize_bytes<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: Visitor<'data>,
-    {
-        Err(GarnishSerializationError::from(
-            "Deserialization of &[u8] not supported, use owned type Vec<u8> instead.",
-        ))
+/// Iterator created by [`GarnishDataDeserializer::values_iter`].
+pub struct GarnishValuesIter<'a, 'data, Data, T, I>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: Into<usize>,
+    Data::Char: Into<char>,
+    Data::Byte: Into<u8>,
+{
+    de: &'a mut GarnishDataDeserializer<'data, Data>,
+    addrs: I,
+    value: PhantomData<T>,
+}
+
+impl<'a, 'data, Data, T, I> Iterator for GarnishValuesIter<'a, 'data, Data, T, I>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+    T: Deserialize<'data>,
+    I: Iterator<Item = Data::Size>,
+{
+    type Item = Result<T, GarnishSerializationError<Data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let addr = self.addrs.next()?;
+        self.de.value_stack.clear();
+        self.de.value_stack.push(addr);
+        self.de.path.clear();
+
+        Some(T::deserialize(&mut *self.de))
     }
+}
 
-    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+/// Address of a value in read only data, converted into a [`GarnishDataDeserializer`] for that value
+/// through [`IntoDeserializer`]. Lets Garnish values be fed into serde's deserializer combinators.
+pub struct GarnishValueRef<'data, Data>
+where
+    Data: GarnishData,
+{
+    data: &'data Data,
+    addr: Data::Size,
+}
+
+impl<'data, Data> GarnishValueRef<'data, Data>
+where
+    Data: GarnishData,
+{
+    pub fn new(data: &'data Data, addr: Data::Size) -> Self {
+        Self { data, addr }
+    }
+}
+
+impl<'data, Data> IntoDeserializer<'data, GarnishSerializationError<Data>>
+    for GarnishValueRef<'data, Data>
+where
+    Data: GarnishStringConversions,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    type Deserializer = GarnishDataDeserializer<'data, Data>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        GarnishDataDeserializer::new_read_only_for_value(self.data, self.addr)
+    }
+}
+
+/// Address of a value in mutable data, converted into a [`GarnishDataDeserializer`] for that value
+/// through [`IntoDeserializer`]. Unlike [`GarnishValueRef`], data doesn't need [`GarnishStringConversions`].
+pub struct GarnishValueMut<'data, Data>
+where
+    Data: GarnishData,
+{
+    data: &'data mut Data,
+    addr: Data::Size,
+}
+
+impl<'data, Data> GarnishValueMut<'data, Data>
+where
+    Data: GarnishData,
+{
+    pub fn new(data: &'data mut Data, addr: Data::Size) -> Self {
+        Self { data, addr }
+    }
+}
+
+impl<'data, Data> IntoDeserializer<'data, GarnishSerializationError<Data>>
+    for GarnishValueMut<'data, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    type Deserializer = GarnishDataDeserializer<'data, Data>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        GarnishDataDeserializer::new_for_value(self.data, self.addr)
+    }
+}
+
+// owned deserializer, needed by IntoDeserializer, defers to the borrowed implementation
+macro_rules! forward_to_borrowed {
+    ($($method:ident($($arg:ident: $ty:ty),*))*) => {
+        $(
+            fn $method<V>(mut self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'data>,
+            {
+                (&mut self).$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'data, Data> Deserializer<'data> for GarnishDataDeserializer<'data, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    type Error = GarnishSerializationError<Data>;
+
+    forward_to_borrowed! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier()
+        deserialize_ignored_any()
+    }
+}
+
+impl<'data, 'a, Data> Deserializer<'data> for &'a mut GarnishDataDeserializer<'data, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    type Error = GarnishSerializationError<Data>;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
         let (t, a) = self.value()?;
         match t {
-            GarnishDataType::ByteList => {
-                let len = self.data.get_byte_list_len(a).or_else(wrap_err)?;
-                let mut bytes = Vec::with_capacity(len.into());
-                let mut i = Data::Size::zero();
-
-                while i < len {
-                    let b = self
-                        .data
-                        .get_byte_list_item(a, Data::size_to_number(i))
-                        .or_else(wrap_err)?;
-                    bytes.push(b.into());
-                    i += Data::Size::one();
+            GarnishDataType::Unit => visitor.visit_unit(),
+            GarnishDataType::True => visitor.visit_bool(true),
+            GarnishDataType::False => visitor.visit_bool(false),
+            GarnishDataType::Number => {
+                // whole numbers are visited as integers, everything else as floats
+                let n = self.data().get_number(a).or_else(wrap_err)?;
+                let f: f64 = n.into();
+                if f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 {
+                    visitor.visit_i64(n.into())
+                } else {
+                    visitor.visit_f64(f)
+                }
+            }
+            GarnishDataType::Type => {
+                let id = self.data().get_type(a).or_else(wrap_err)? as u8;
+                visitor.visit_u8(id)
+            }
+            GarnishDataType::Byte => {
+                let b = self.data().get_byte(a).or_else(wrap_err)?;
+                visitor.visit_u8(b.into())
+            }
+            GarnishDataType::Char => self.deserialize_char(visitor),
+            GarnishDataType::CharList | GarnishDataType::Symbol => self.deserialize_string(visitor),
+            GarnishDataType::ByteList => self.deserialize_byte_buf(visitor),
+            GarnishDataType::Pair => self.deserialize_tuple(2, visitor),
+            GarnishDataType::Range => {
+                let (start, end) = self.data().get_range(a).or_else(wrap_err)?;
+                visitor.visit_seq(ListAccessor {
+                    de: self,
+                    items: vec![start, end],
+                    pos: 0,
+                    index: 0,
+                    key: None,
+                })
+            }
+            // lists made up of only associations are treated as maps
+            GarnishDataType::List => {
+                let items = gather_list_items(a, self.data())?;
+                let mut associations = !items.is_empty();
+                for item in items.iter() {
+                    if self.data().get_data_type(*item).or_else(wrap_err)? != GarnishDataType::Pair
+                    {
+                        associations = false;
+                        break;
+                    }
                 }
 
-                visitor.visit_byte_buf(bytes)
+                match associations {
+                    true => self.deserialize_map(visitor),
+                    false => self.deserialize_seq(visitor),
+                }
+            }
+            GarnishDataType::Concatenation | GarnishDataType::Slice => {
+                self.deserialize_seq(visitor)
             }
             t => Err(GarnishSerializationError::from(
-                format!("Expected ByteList, found {:?}", t).as_str(),
+                format!("Cannot deserialize {:?} without a target type.", t).as_str(),
             )),
         }
     }
 
-    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
         let (t, _a) = self.value()?;
+
         match t {
-            GarnishDataType::Unit => visitor.visit_none(),
-            _ => visitor.visit_some(self),
+            GarnishDataType::True => visitor.visit_bool(true),
+            GarnishDataType::False => visitor.visit_bool(false),
+            t => Err(GarnishSerializationError::type_mismatch(
+                &[GarnishDataType::True, GarnishDataType::False],
+                t,
+            )),
         }
     }
 
-    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        let (t, _a) = self.value()?;
-        match t {
-            GarnishDataType::Unit => visitor.visit_unit(),
-            t => Err(GarnishSerializationError::from(
-                format!("Expected Unit, found {:?}", t).as_str(),
-            )),
-        }
+        self.deserialize_integer(visitor, i8::MIN as f64, i8::MAX as f64, V::visit_i8)
     }
 
-    fn deserialize_unit_struct<V>(
-        self,
-        _name: &'static str,
-        visitor: V,
-    ) -> Result<V::Value, Self::Error>
+    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        self.deserialize_unit(visitor)
+        self.deserialize_integer(visitor, i16::MIN as f64, i16::MAX as f64, V::visit_i16)
     }
 
-    fn deserialize_newtype_struct<V>(
-        self,
-        _name: &'static str,
-        visitor: V,
-    ) -> Result<V::Value, Self::Error>
+    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_newtype_struct(self)
+        self.deserialize_integer(visitor, i32::MIN as f64, i32::MAX as f64, V::visit_i32)
     }
 
-    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_seq(ListAccessor::new(self)?)
+        self.deserialize_integer(visitor, i64::MIN as f64, i64::MAX as f64, V::visit_i64)
     }
 
-    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_seq(ListAccessor::new_with_max(self, len)?)
+        if let Some(wide) = self.number_128 {
+            return self.deserialize_integer_with(
+                visitor,
+                i128::MIN as f64,
+                i128::MAX as f64,
+                wide.into_i128,
+                V::visit_i128,
+            );
+        }
+
+        // GarnishNumberConversions is limited to 64 bits
+        self.deserialize_integer(
+            visitor,
+            i64::MIN as f64,
+            i64::MAX as f64,
+            |visitor, v: i64| visitor.visit_i128(i128::from(v)),
+        )
     }
 
-    fn deserialize_tuple_struct<V>(
-        self,
-        _name: &'static str,
-        len: usize,
-        visitor: V,
-    ) -> Result<V::Value, Self::Error>
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_seq(ListAccessor::new_with_max(self, len)?)
+        if let Some(id) = self.type_id()? {
+            return visitor.visit_u8(id);
+        }
+
+        let (t, a) = self.value()?;
+        if t == GarnishDataType::Byte {
+            return visitor.visit_u8(self.data().get_byte(a).or_else(wrap_err)?.into());
+        }
+
+        self.deserialize_integer(visitor, u8::MIN as f64, u8::MAX as f64, V::visit_u8)
     }
 
-    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_map(ListAccessor::new(self)?)
+        self.deserialize_integer(visitor, u16::MIN as f64, u16::MAX as f64, V::visit_u16)
     }
 
-    fn deserialize_struct<V>(
-        self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
-        visitor: V,
-    ) -> Result<V::Value, Self::Error>
+    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_map(ListAccessor::new(self)?)
+        if let Some(id) = self.type_id()? {
+            return visitor.visit_u32(u32::from(id));
+        }
+
+        self.deserialize_integer(visitor, u32::MIN as f64, u32::MAX as f64, V::visit_u32)
     }
 
-    fn deserialize_enum<V>(
-        self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
-        visitor: V,
-    ) -> Result<V::Value, Self::Error>
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        visitor.visit_enum(EnumAccessor::new(self)?)
+        self.deserialize_integer(visitor, u64::MIN as f64, u64::MAX as f64, V::visit_u64)
     }
 
-    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        self.deserialize_string(visitor)
+        if let Some(wide) = self.number_128 {
+            return self.deserialize_integer_with(
+                visitor,
+                u128::MIN as f64,
+                u128::MAX as f64,
+                wide.into_u128,
+                V::visit_u128,
+            );
+        }
+
+        // GarnishNumberConversions is limited to 64 bits
+        self.deserialize_integer(
+            visitor,
+            u64::MIN as f64,
+            u64::MAX as f64,
+            |visitor, v: u64| visitor.visit_u128(u128::from(v)),
+        )
     }
 
-    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'data>,
     {
-        unimplemented!()
+        self.deserialize_primitive(
+            visitor,
+            Data::get_number,
+            V::visit_f32,
+            GarnishDataType::Number,
+        )
     }
-}
 
-struct ListAccessor<'a, 'data, Data>
-where
-    'data: 'a,
-    Data: GarnishData,
-    Data::Number: GarnishNumberConversions,
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        self.deserialize_primitive(
+            visitor,
+            Data::get_number,
+            V::visit_f64,
+            GarnishDataType::Number,
+        )
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        let (t, a) = self.value()?;
+        match t {
+            GarnishDataType::Char => {
+                visitor.visit_char(self.data().get_char(a).or_else(wrap_err)?.into())
+            }
+            // single character list, CharSerializationBehavior::CharList
+            GarnishDataType::CharList => {
+                let len: usize = self.data().get_char_list_len(a).or_else(wrap_err)?.into();
+                if len != 1 {
+                    return Err(GarnishSerializationError::from(
+                        format!(
+                            "Expected CharList of length 1 for char, found length {}",
+                            len
+                        )
+                        .as_str(),
+                    ));
+                }
+
+                let c: char = self
+                    .data()
+                    .get_char_list_item(a, Data::Number::zero())
+                    .or_else(wrap_err)?
+                    .into();
+
+                visitor.visit_char(c)
+            }
+            // code point, CharSerializationBehavior::CodePoint
+            GarnishDataType::Number => {
+                let n = self.data().get_number(a).or_else(wrap_err)?;
+                let invalid = || {
+                    GarnishSerializationError::from(
+                        format!("{} is not a valid char code point", n).as_str(),
+                    )
+                };
+
+                // checked before converting, negative and fractional numbers would be truncated
+                let f: f64 = n.into();
+                if f.fract() != 0.0 || f < 0.0 || f > u32::MAX as f64 {
+                    return Err(invalid());
+                }
+                let c = char::from_u32(n.into()).ok_or_else(invalid)?;
+                visitor.visit_char(c)
+            }
+            t => Err(GarnishSerializationError::type_mismatch(
+                &[
+                    GarnishDataType::Char,
+                    GarnishDataType::CharList,
+                    GarnishDataType::Number,
+                ],
+                t,
+            )),
+        }
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        if let DataAccess::ReadOnly(data, _, Some((get_str, _))) = self.data {
+            let (_, a) = self.value()?;
+            if let Some(s) = get_str(data, a) {
+                return visitor.visit_borrowed_str(s);
+            }
+        }
+
+        Err(GarnishSerializationError::from(
+            "Deserialization of &str not supported, use owned type String instead.",
+        ))
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        let allow_symbol =
+            self.options.symbol_string_behavior == SymbolStringBehavior::AllowSymbolAsString;
+        self.deserialize_string_like(visitor, allow_symbol)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        if let DataAccess::ReadOnly(data, _, Some((_, get_bytes))) = self.data {
+            let (_, a) = self.value()?;
+            if let Some(bytes) = get_bytes(data, a) {
+                return visitor.visit_borrowed_bytes(bytes);
+            }
+        }
+
+        Err(GarnishSerializationError::from(
+            "Deserialization of &[u8] not supported, use owned type Vec<u8> instead.",
+        ))
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        let (t, a) = self.value()?;
+        match t {
+            GarnishDataType::ByteList => visitor.visit_byte_buf(gather_bytes(a, self.data())?),
+            t => Err(GarnishSerializationError::type_mismatch(
+                &[GarnishDataType::ByteList],
+                t,
+            )),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        let (t, a) = self.value()?;
+        match t {
+            GarnishDataType::Unit => visitor.visit_none(),
+            GarnishDataType::Symbol
+                if self.options.optional_behavior == OptionalBehavior::UnitSymbol
+                    && self.data().get_symbol(a).or_else(wrap_err)?
+                        == Data::parse_symbol(self.options.none_symbol).or_else(wrap_err)? =>
+            {
+                visitor.visit_none()
+            }
+            GarnishDataType::List if self.options.preserve_option_nesting => {
+                match self.option_wrapper_value(a)? {
+                    Some(value) => {
+                        self.push_value(value)?;
+                        let r = visitor.visit_some(&mut *self);
+                        self.value_stack.pop();
+                        r
+                    }
+                    None => visitor.visit_some(self),
+                }
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        let (t, _a) = self.value()?;
+        match t {
+            GarnishDataType::Unit => visitor.visit_unit(),
+            t => Err(GarnishSerializationError::type_mismatch(
+                &[GarnishDataType::Unit],
+                t,
+            )),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        // None from optional::unit_symbol
+        if name == GARNISH_NONE_SYMBOL_NAME {
+            let (t, a) = self.value()?;
+            if t == GarnishDataType::Symbol
+                && self.data().get_symbol(a).or_else(wrap_err)?
+                    == Data::parse_symbol(self.options.none_symbol).or_else(wrap_err)?
+            {
+                return visitor.visit_none();
+            }
+        }
+
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        if let (GarnishDataType::ByteList, a) = self.value()? {
+            return self.deserialize_byte_list_seq(a, visitor);
+        }
+
+        visitor.visit_seq(ListAccessor::new(self)?)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        if let (GarnishDataType::ByteList, a) = self.value()? {
+            return self.deserialize_byte_list_seq(a, visitor);
+        }
+
+        visitor.visit_seq(ListAccessor::new_for_tuple(self, len)?)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        visitor.visit_seq(ListAccessor::new_for_tuple(self, len)?)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        visitor.visit_map(ListAccessor::new(self)?)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        let (t, a) = self.value()?;
+        match t {
+            GarnishDataType::Range => {
+                // any struct made of only start and end fields, in either order
+                if fields.len() != 2 || !fields.contains(&"start") || !fields.contains(&"end") {
+                    return Err(GarnishSerializationError::from(
+                        format!(
+                            "Range can only be deserialized into a struct with exactly the fields start and end, found {:?}.",
+                            fields
+                        )
+                        .as_str(),
+                    ));
+                }
+
+                let (start, end) = self.data().get_range(a).or_else(wrap_err)?;
+                // Garnish ranges are inclusive, only std::ops::Range needs its end adjusted
+                visitor.visit_map(RangeAccessor {
+                    de: self,
+                    start,
+                    end,
+                    exclusive: name == "Range",
+                    index: 0,
+                })
+            }
+            // serialized with EmptyStructBehavior::Unit
+            GarnishDataType::Unit => visitor.visit_map(ListAccessor {
+                de: self,
+                items: vec![],
+                pos: 0,
+                index: 0,
+                key: None,
+            }),
+            _ => visitor.visit_map(ListAccessor::new(self)?),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        // variant values are left on the stack by the accessor
+        let depth = self.value_stack.len();
+        let r = visitor.visit_enum(EnumAccessor::new(&mut *self, variants)?);
+        self.value_stack.truncate(depth);
+
+        r
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        // field and variant names are stored as symbols
+        // number keys come from maps flattened into a struct
+        let (t, _) = self.value()?;
+        match t {
+            GarnishDataType::Number => self.deserialize_any(visitor),
+            _ => self.deserialize_string_like(visitor, true),
+        }
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'data>,
+    {
+        // value only needs to exist
+        // nested values are referenced by address so nothing else needs to be consumed
+        let (_t, _a) = self.value()?;
+        visitor.visit_unit()
+    }
+}
+
+struct ListAccessor<'a, 'data, Data>
+where
+    'data: 'a,
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
     Data::Size: From<usize>,
     Data::Size: Into<usize>,
     Data::Char: From<char>,
@@ -493,6 +1358,10 @@ where
 {
     de: &'a mut GarnishDataDeserializer<'data, Data>,
     items: Vec<Data::Size>,
+    // next item to be read from items
+    pos: usize,
+    index: usize,
+    key: Option<Data::Size>,
 }
 
 impl<'a, 'data, Data> ListAccessor<'a, 'data, Data>
@@ -518,21 +1387,27 @@ where
     ) -> Result<Self, GarnishSerializationError<Data>> {
         let (t, a) = de.value()?;
         let items = match t {
-            GarnishDataType::List => gather_list_items(a, de.data)?,
-            GarnishDataType::Concatenation => gather_concat_items(a, de.data)?,
+            GarnishDataType::List => gather_list_items(a, de.data())?,
+            GarnishDataType::Concatenation => {
+                gather_concat_items(a, de.data(), de.options.flatten_lists_in_concatenation)?
+            }
             GarnishDataType::Slice => {
-                let (list_ref, range_ref) = de.data.get_slice(a).or_else(wrap_err)?;
-                let list_type = de.data.get_data_type(list_ref).or_else(wrap_err)?;
+                let (list_ref, range_ref) = de.data().get_slice(a).or_else(wrap_err)?;
+                let list_type = de.data().get_data_type(list_ref).or_else(wrap_err)?;
 
-                let (start_ref, end_ref) = de.data.get_range(range_ref).or_else(wrap_err)?;
+                let (start_ref, end_ref) = de.data().get_range(range_ref).or_else(wrap_err)?;
                 let (start, end): (usize, usize) = (
-                    de.data.get_number(start_ref).or_else(wrap_err)?.into(),
-                    de.data.get_number(end_ref).or_else(wrap_err)?.into(),
+                    de.data().get_number(start_ref).or_else(wrap_err)?.into(),
+                    de.data().get_number(end_ref).or_else(wrap_err)?.into(),
                 );
 
                 let items = match list_type {
-                    GarnishDataType::List => gather_list_items(list_ref, de.data)?,
-                    GarnishDataType::Concatenation => gather_concat_items(list_ref, de.data)?,
+                    GarnishDataType::List => gather_list_items(list_ref, de.data())?,
+                    GarnishDataType::Concatenation => gather_concat_items(
+                        list_ref,
+                        de.data(),
+                        de.options.flatten_lists_in_concatenation,
+                    )?,
                     t => Err(GarnishSerializationError::from(
                         format!("{:?} Slice cannot be converted to sequence.", t).as_str(),
                     ))?,
@@ -552,6 +1427,11 @@ where
                         .collect::<Vec<Data::Size>>()
                 }
             }
+            // pairs can be treated as a 2 item tuple
+            GarnishDataType::Pair if max == 2 => {
+                let (left, right) = de.data().get_pair(a).or_else(wrap_err)?;
+                vec![left, right]
+            }
             // Imply list of length 1 for all other types
             _ => vec![a],
         };
@@ -569,15 +1449,40 @@ where
 
         Ok(Self {
             de,
-            // reverse so items can be popped in order
-            items: items.into_iter().rev().collect(),
+            items,
+            pos: 0,
+            index: 0,
+            key: None,
         })
     }
+
+    pub fn new_for_tuple(
+        de: &'a mut GarnishDataDeserializer<'data, Data>,
+        len: usize,
+    ) -> Result<Self, GarnishSerializationError<Data>> {
+        let accessor = Self::new_with_max(de, len)?;
+        if accessor.items.is_empty() && len > 0 {
+            return Err(GarnishSerializationError::from(
+                format!("Expected tuple of length {}, found empty list.", len).as_str(),
+            ));
+        }
+
+        Ok(accessor)
+    }
+
+    fn next_item(&mut self) -> Option<Data::Size> {
+        let item = self.items.get(self.pos).copied();
+        if item.is_some() {
+            self.pos += 1;
+        }
+        item
+    }
 }
 
 fn gather_concat_items<Data: GarnishData>(
     concat_ref: Data::Size,
     data: &Data,
+    flatten_lists: bool,
 ) -> Result<Vec<Data::Size>, GarnishSerializationError<Data>>
 where
     Data: GarnishData,
@@ -589,7 +1494,8 @@ where
     Data::Byte: From<u8>,
     Data::Byte: Into<u8>,
 {
-    let mut items = vec![];
+    // at least the left and right of the root concatenation
+    let mut items = Vec::with_capacity(2);
     let mut cat_stack = vec![concat_ref];
     while !cat_stack.is_empty() {
         let current = match cat_stack.pop() {
@@ -603,6 +1509,9 @@ where
                 cat_stack.push(right);
                 cat_stack.push(left);
             }
+            GarnishDataType::List if flatten_lists => {
+                items.extend(gather_list_items(current, data)?);
+            }
             _ => items.push(current),
         }
     }
@@ -610,6 +1519,34 @@ where
     Ok(items)
 }
 
+fn gather_bytes<Data>(
+    byte_list_ref: Data::Size,
+    data: &Data,
+) -> Result<Vec<u8>, GarnishSerializationError<Data>>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    let len = data.get_byte_list_len(byte_list_ref).or_else(wrap_err)?;
+    let mut bytes = Vec::with_capacity(len.into());
+    let mut i = Data::Size::zero();
+    while i < len {
+        let b = data
+            .get_byte_list_item(byte_list_ref, Data::size_to_number(i))
+            .or_else(wrap_err)?;
+        bytes.push(b.into());
+        i += Data::Size::one();
+    }
+
+    Ok(bytes)
+}
+
 fn gather_list_items<Data: GarnishData>(
     list_ref: Data::Size,
     data: &Data,
@@ -626,7 +1563,7 @@ where
 {
     let len = data.get_list_len(list_ref).or_else(wrap_err)?;
     let mut i = Data::Size::zero();
-    let mut items = vec![];
+    let mut items = Vec::with_capacity(len.into());
     while i < len {
         let list_item = data
             .get_list_item(list_ref, Data::size_to_number(i))
@@ -655,19 +1592,29 @@ where
     where
         T: DeserializeSeed<'data>,
     {
-        if let Some(item) = self.items.pop() {
-            self.de.value_stack.push(item);
+        if let Some(item) = self.next_item() {
+            self.de.push_value(item)?;
+            self.de.path.push(PathSegment::Index(self.index));
+            self.index += 1;
 
-            let r = seed.deserialize(&mut *self.de).map(Some);
+            let r = seed
+                .deserialize(&mut *self.de)
+                .map(Some)
+                .map_err(|e| self.de.with_path(e));
 
             // done with list item
             self.de.value_stack.pop();
+            self.de.path.pop();
 
             r
         } else {
             Ok(None)
         }
     }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len() - self.pos)
+    }
 }
 
 impl<'a, 'data, Data> MapAccess<'data> for ListAccessor<'a, 'data, Data>
@@ -687,9 +1634,9 @@ where
     where
         K: DeserializeSeed<'data>,
     {
-        if let Some(item) = self.items.pop() {
-            let (key, value) = self.de.data.get_pair(item).or_else(wrap_err)?;
-            self.de.value_stack.push(key);
+        if let Some(item) = self.next_item() {
+            let (key, value) = self.de.data().get_pair(item).or_else(wrap_err)?;
+            self.de.push_value(key)?;
 
             let r = seed.deserialize(&mut *self.de).map(Some);
 
@@ -697,7 +1644,8 @@ where
             self.de.value_stack.pop();
 
             // set up value for next_value_seed
-            self.de.value_stack.push(value);
+            self.de.push_value(value)?;
+            self.key = Some(key);
 
             r
         } else {
@@ -713,15 +1661,32 @@ where
         // so don't need to check length again
         // value addr was set up by next_key_seed
         // just need to deserialize
-        let r = seed.deserialize(&mut *self.de);
+        let pushed = match self.key.take() {
+            Some(key) => {
+                self.de.path.push(PathSegment::Key(key));
+                true
+            }
+            None => false,
+        };
+
+        let r = seed
+            .deserialize(&mut *self.de)
+            .map_err(|e| self.de.with_path(e));
         // remove value addr
         self.de.value_stack.pop();
+        if pushed {
+            self.de.path.pop();
+        }
 
         r
     }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len() - self.pos)
+    }
 }
 
-struct EnumAccessor<'a, 'data, Data>
+struct RangeAccessor<'a, 'data, Data>
 where
     'data: 'a,
     Data: GarnishData,
@@ -734,9 +1699,13 @@ where
     Data::Byte: Into<u8>,
 {
     de: &'a mut GarnishDataDeserializer<'data, Data>,
+    start: Data::Size,
+    end: Data::Size,
+    exclusive: bool,
+    index: usize,
 }
 
-impl<'a, 'data, Data> EnumAccessor<'a, 'data, Data>
+impl<'a, 'data, Data> MapAccess<'data> for RangeAccessor<'a, 'data, Data>
 where
     Data: GarnishData,
     Data::Number: GarnishNumberConversions,
@@ -747,15 +1716,122 @@ where
     Data::Byte: From<u8>,
     Data::Byte: Into<u8>,
 {
-    pub fn new(
-        de: &'a mut GarnishDataDeserializer<'data, Data>,
-    ) -> Result<Self, GarnishSerializationError<Data>> {
-        Ok(Self { de })
-    }
-}
+    type Error = GarnishSerializationError<Data>;
 
-impl<'a, 'data, Data> EnumAccess<'data> for EnumAccessor<'a, 'data, Data>
-where
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'data>,
+    {
+        let key = match self.index {
+            0 => "start",
+            1 => "end",
+            _ => return Ok(None),
+        };
+
+        let deserializer: StrDeserializer<'_, GarnishSerializationError<Data>> =
+            key.into_deserializer();
+        seed.deserialize(deserializer).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'data>,
+    {
+        self.index += 1;
+        match self.index {
+            1 => self.deserialize_value(self.start, seed),
+            _ if self.exclusive => {
+                let n = self.de.data().get_number(self.end).or_else(wrap_err)?;
+                let (i, f): (i64, f64) = (n.into(), n.into());
+                if i as f64 != f {
+                    return Err(GarnishSerializationError::from(
+                        "Range end must be an integer to convert to an exclusive Range.",
+                    ));
+                }
+
+                let end = i.checked_add(1).ok_or_else(|| {
+                    GarnishSerializationError::from(
+                        format!(
+                            "Range end {} has no next integer to convert to an exclusive Range.",
+                            i
+                        )
+                        .as_str(),
+                    )
+                })?;
+
+                let deserializer: I64Deserializer<GarnishSerializationError<Data>> =
+                    end.into_deserializer();
+                seed.deserialize(deserializer)
+            }
+            _ => self.deserialize_value(self.end, seed),
+        }
+    }
+}
+
+impl<'a, 'data, Data> RangeAccessor<'a, 'data, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    fn deserialize_value<V>(
+        &mut self,
+        addr: Data::Size,
+        seed: V,
+    ) -> Result<V::Value, GarnishSerializationError<Data>>
+    where
+        V: DeserializeSeed<'data>,
+    {
+        self.de.push_value(addr)?;
+        let r = seed.deserialize(&mut *self.de);
+        self.de.value_stack.pop();
+
+        r
+    }
+}
+
+struct EnumAccessor<'a, 'data, Data>
+where
+    'data: 'a,
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    de: &'a mut GarnishDataDeserializer<'data, Data>,
+    variants: &'static [&'static str],
+}
+
+impl<'a, 'data, Data> EnumAccessor<'a, 'data, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    pub fn new(
+        de: &'a mut GarnishDataDeserializer<'data, Data>,
+        variants: &'static [&'static str],
+    ) -> Result<Self, GarnishSerializationError<Data>> {
+        Ok(Self { de, variants })
+    }
+}
+
+impl<'a, 'data, Data> EnumAccess<'data> for EnumAccessor<'a, 'data, Data>
+where
     Data: GarnishData,
     Data::Number: GarnishNumberConversions,
     Data::Size: From<usize>,
@@ -773,45 +1849,86 @@ where
         V: DeserializeSeed<'data>,
     {
         let (t, a) = self.de.value()?;
-        let sym_a = match t {
+        let variant_a = match t {
             GarnishDataType::List => {
-                let first = self
-                    .de
-                    .data
-                    .get_list_item(a, Data::Number::zero())
-                    .or_else(wrap_err)?;
+                let (tag, value) = self.de.tagged_list_parts(a)?;
 
                 // need to push variant value to stack for access after identification
-                let second = self
-                    .de
-                    .data
-                    .get_list_item(a, Data::Number::one())
-                    .or_else(wrap_err)?;
+                self.de.push_value(value)?;
 
-                self.de.value_stack.push(second);
+                tag
+            }
+            // newtype variant written as ;Variant = value
+            GarnishDataType::Pair => {
+                let (tag, value) = self.de.data().get_pair(a).or_else(wrap_err)?;
+                self.de.push_value(value)?;
 
-                first
+                tag
             }
-            GarnishDataType::Symbol => a,
-            _ => Err(GarnishSerializationError::from(
-                format!("Expected List or Symbol for variant, found {:?}", t).as_str(),
+            GarnishDataType::Symbol | GarnishDataType::Number | GarnishDataType::CharList => a,
+            _ => Err(GarnishSerializationError::type_mismatch(
+                &[
+                    GarnishDataType::List,
+                    GarnishDataType::Pair,
+                    GarnishDataType::Symbol,
+                    GarnishDataType::Number,
+                    GarnishDataType::CharList,
+                ],
+                t,
             ))?,
         };
 
-        let sym = self.de.create_symbol_string(sym_a)?;
-        // stored as full name should be split with following pattern
-        // resulting in 2 elements
-        let mut parts = sym.split("::");
-        parts.next(); // drop first value, don't need currently
-        let enum_part = parts.next().ok_or_else(|| {
-            GarnishSerializationError::from(
-                format!("Could not get enum value from symbols string {:?}", sym).as_str(),
-            )
-        })?;
+        let variant_value = match self.de.data().get_data_type(variant_a).or_else(wrap_err)? {
+            // serialized with VariantNameBehavior::Index
+            GarnishDataType::Number => {
+                let index: u32 = self
+                    .de
+                    .data()
+                    .get_number(variant_a)
+                    .or_else(wrap_err)?
+                    .into();
+                let deserializer: U32Deserializer<GarnishSerializationError<Data>> =
+                    index.into_deserializer();
+                seed.deserialize(deserializer)?
+            }
+            _ => {
+                let unknown_behavior = self.de.options.unknown_variant_behavior;
+                let variants = self.variants;
+                let (value, captured) = self.de.with_string(variant_a, |sym| {
+                    // names are stored short, as Variant, or full, as Enum::Variant
+                    // variants may be renamed to include the separator,
+                    // so try each suffix after a separator, starting with the whole name
+                    let enum_part = std::iter::successors(Some(sym), |name| {
+                        name.split_once("::").map(|(_, rest)| rest)
+                    })
+                    .find(|name| variants.contains(name))
+                    .unwrap_or_else(|| sym.rsplit_once("::").map_or(sym, |(_, variant)| variant));
+
+                    let (name, captured) = match unknown_behavior {
+                        UnknownVariantBehavior::Capture(fallback)
+                            if !variants.contains(&enum_part) =>
+                        {
+                            (fallback, true)
+                        }
+                        _ => (enum_part, false),
+                    };
+
+                    let deserializer: StrDeserializer<'_, GarnishSerializationError<Data>> =
+                        name.into_deserializer();
+                    Ok((seed.deserialize(deserializer)?, captured))
+                })?;
+
+                if captured {
+                    // captured variant's value is the unknown name instead of any stored value
+                    if matches!(t, GarnishDataType::List | GarnishDataType::Pair) {
+                        self.de.value_stack.pop();
+                    }
+                    self.de.push_value(variant_a)?;
+                }
 
-        let deserializer: StrDeserializer<'_, GarnishSerializationError<Data>> =
-            enum_part.into_deserializer();
-        let variant_value = seed.deserialize(deserializer)?;
+                value
+            }
+        };
 
         Ok((variant_value, self))
     }
@@ -870,14 +1987,19 @@ mod tests {
     use std::fmt::{Debug, Formatter};
     use std::marker::PhantomData;
 
-    use serde::de::{DeserializeOwned, Error, Visitor};
-    use serde::{Deserialize, Deserializer};
+    use serde::de::{DeserializeOwned, Error, SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize};
 
     use garnish_lang_simple_data::{DataError, SimpleGarnishData, SimpleNumber};
-    use garnish_lang_traits::GarnishData;
+    use garnish_lang_traits::{GarnishData, GarnishDataType};
 
     use crate::deserializer::GarnishDataDeserializer;
-    use crate::error::GarnishSerializationError;
+    use crate::error::{GarnishSerializationError, GarnishSerializationErrorKind};
+    use crate::test_data::UncachedGarnishData;
+    use crate::{
+        GarnishDataSerializer, GarnishDeserializationOptions, GarnishSerializationOptions,
+        GarnishValueTree, TagPosition, UnknownVariantBehavior, VariantNameBehavior,
+    };
 
     fn deserialize<SetupF, Type>(
         setup: SetupF,
@@ -925,6 +2047,21 @@ mod tests {
         assert_deserializes(|data| data.add_false(), false);
     }
 
+    #[test]
+    fn deserialize_bool_type_mismatch() {
+        let err =
+            deserialize::<_, bool>(|data| data.add_number(SimpleNumber::Integer(100))).unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &GarnishSerializationErrorKind::TypeMismatch {
+                expected: vec![GarnishDataType::True, GarnishDataType::False],
+                found: GarnishDataType::Number,
+            }
+        );
+        assert_eq!(err.to_string(), "Expected True or False, found Number");
+    }
+
     #[test]
     fn deserialize_i8() {
         assert_deserializes(|data| data.add_number(SimpleNumber::Integer(100)), 100i8);
@@ -950,6 +2087,69 @@ mod tests {
         assert_deserializes(|data| data.add_number(SimpleNumber::Integer(100)), 100u8);
     }
 
+    #[test]
+    fn deserialize_u8_from_byte() {
+        assert_deserializes(|data| data.add_byte(200), 200u8);
+    }
+
+    #[test]
+    fn deserialize_u16_from_byte_fails() {
+        assert_fails::<_, u16>(|data| data.add_byte(200));
+    }
+
+    #[test]
+    fn deserialize_u8_from_type() {
+        assert_deserializes(|data| data.add_type(GarnishDataType::Char), 4u8);
+    }
+
+    #[test]
+    fn deserialize_u32_from_type() {
+        assert_deserializes(|data| data.add_type(GarnishDataType::Number), 2u32);
+    }
+
+    #[repr(u8)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    enum StoredType {
+        Unit = 1,
+        Number = 2,
+        Char = 4,
+    }
+
+    impl StoredType {
+        fn data_type(self) -> GarnishDataType {
+            match self {
+                StoredType::Unit => GarnishDataType::Unit,
+                StoredType::Number => GarnishDataType::Number,
+                StoredType::Char => GarnishDataType::Char,
+            }
+        }
+    }
+
+    // same shape as serde_repr's derive, reads the discriminant as a u8
+    impl<'de> Deserialize<'de> for StoredType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            match u8::deserialize(deserializer)? {
+                1 => Ok(StoredType::Unit),
+                2 => Ok(StoredType::Number),
+                4 => Ok(StoredType::Char),
+                v => Err(serde::de::Error::custom(format!(
+                    "invalid discriminant {}",
+                    v
+                ))),
+            }
+        }
+    }
+
+    #[test]
+    fn repr_enum_stored_as_type_round_trip() {
+        for kind in [StoredType::Unit, StoredType::Number, StoredType::Char] {
+            assert_deserializes(|data| data.add_type(kind.data_type()), kind);
+        }
+    }
+
     #[test]
     fn deserialize_u16() {
         assert_deserializes(|data| data.add_number(SimpleNumber::Integer(100)), 100u16);
@@ -965,6 +2165,160 @@ mod tests {
         assert_deserializes(|data| data.add_number(SimpleNumber::Integer(100)), 100u64);
     }
 
+    #[test]
+    fn deserialize_i128() {
+        assert_deserializes(
+            |data| data.add_number(SimpleNumber::Integer(-100)),
+            -100i128,
+        );
+    }
+
+    #[test]
+    fn deserialize_u128() {
+        assert_deserializes(|data| data.add_number(SimpleNumber::Integer(100)), 100u128);
+    }
+
+    fn deserialize_json_compat<T: for<'a> Deserialize<'a>>(
+        n: f64,
+    ) -> Result<T, GarnishSerializationError<SimpleGarnishData>> {
+        let mut data = SimpleGarnishData::new();
+        let addr = data.add_number(SimpleNumber::Float(n)).unwrap();
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().json_compat(true),
+        );
+
+        T::deserialize(&mut deserializer)
+    }
+
+    fn deserialize_with_options<T: for<'a> Deserialize<'a>>(
+        options: GarnishDeserializationOptions,
+        add: impl FnOnce(&mut SimpleGarnishData) -> usize,
+    ) -> Result<T, GarnishSerializationError<SimpleGarnishData>> {
+        let mut data = SimpleGarnishData::new();
+        let addr = add(&mut data);
+        let mut deserializer =
+            GarnishDataDeserializer::new_for_value_with_options(&mut data, addr, options);
+
+        T::deserialize(&mut deserializer)
+    }
+
+    #[test]
+    fn deserialize_i32_lenient_truncates() {
+        let options = GarnishDeserializationOptions::new().lenient_number_coercion(true);
+
+        let v: i32 = deserialize_with_options(options, |data| {
+            data.add_number(SimpleNumber::Float(-2.7)).unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(v, -2);
+    }
+
+    #[test]
+    fn deserialize_u8_lenient_still_range_checked() {
+        let options = GarnishDeserializationOptions::new().lenient_number_coercion(true);
+
+        let r = deserialize_with_options::<u8>(options, |data| {
+            data.add_number(SimpleNumber::Float(300.5)).unwrap()
+        });
+
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn deserialize_i32_fractional_fails_without_lenient() {
+        assert_fails::<_, i32>(|data| data.add_number(SimpleNumber::Float(2.7)));
+    }
+
+    #[test]
+    fn deserialize_option_custom_none_symbol() {
+        let options = GarnishDeserializationOptions::new()
+            .optional_behavior(crate::OptionalBehavior::UnitSymbol)
+            .none_symbol("nil");
+
+        let none: Option<i32> =
+            deserialize_with_options(options, |data| data.parse_add_symbol("nil").unwrap())
+                .unwrap();
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn deserialize_option_none_symbol_with_lenient_numbers() {
+        let options = GarnishDeserializationOptions::new()
+            .optional_behavior(crate::OptionalBehavior::UnitSymbol)
+            .lenient_number_coercion(true);
+
+        let none: Option<i32> =
+            deserialize_with_options(options, |data| data.parse_add_symbol("none").unwrap())
+                .unwrap();
+        assert_eq!(none, None);
+
+        let options = GarnishDeserializationOptions::new()
+            .optional_behavior(crate::OptionalBehavior::UnitSymbol)
+            .lenient_number_coercion(true);
+
+        let some: Option<i32> = deserialize_with_options(options, |data| {
+            data.add_number(SimpleNumber::Float(5.5)).unwrap()
+        })
+        .unwrap();
+        assert_eq!(some, Some(5));
+    }
+
+    #[test]
+    fn deserialize_u64_json_compat_at_limit() {
+        assert_eq!(
+            deserialize_json_compat::<u64>(9_007_199_254_740_992.0).unwrap(),
+            9_007_199_254_740_992u64
+        );
+    }
+
+    #[test]
+    fn deserialize_u64_json_compat_beyond_limit() {
+        let err = deserialize_json_compat::<u64>(18_014_398_509_481_984.0).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .ends_with("exceeds the 2^53 precision of JSON numbers"));
+    }
+
+    #[test]
+    fn deserialize_u64_beyond_json_limit_without_option() {
+        assert_deserializes(
+            |data| data.add_number(SimpleNumber::Float(18_014_398_509_481_984.0)),
+            18_014_398_509_481_984u64,
+        );
+    }
+
+    #[test]
+    fn deserialize_u8_out_of_range() {
+        assert_fails::<_, u8>(|data| data.add_number(SimpleNumber::Integer(300)));
+    }
+
+    #[test]
+    fn deserialize_u8_negative() {
+        assert_fails::<_, u8>(|data| data.add_number(SimpleNumber::Integer(-1)));
+    }
+
+    #[test]
+    fn deserialize_i8_out_of_range_message() {
+        let err =
+            deserialize::<_, i8>(|data| data.add_number(SimpleNumber::Integer(300))).unwrap_err();
+
+        assert_eq!(err.to_string(), "Number 300 is out of range for i8");
+    }
+
+    #[test]
+    fn deserialize_i32_from_whole_float() {
+        assert_deserializes(|data| data.add_number(SimpleNumber::Float(100.0)), 100i32);
+    }
+
+    #[test]
+    fn deserialize_i32_from_fractional_float() {
+        assert_fails::<_, i32>(|data| data.add_number(SimpleNumber::Float(100.5)));
+    }
+
     #[test]
     fn deserialize_f32() {
         assert_deserializes(|data| data.add_number(SimpleNumber::Float(100.0)), 100.0f32);
@@ -980,6 +2334,52 @@ mod tests {
         assert_deserializes(|data| data.add_char('a'), 'a');
     }
 
+    #[test]
+    fn deserialize_char_from_single_char_list() {
+        assert_deserializes(|data| data.parse_add_char_list("a"), 'a');
+    }
+
+    #[test]
+    fn deserialize_char_from_long_char_list() {
+        assert_fails::<_, char>(|data| data.parse_add_char_list("\"ab\""));
+    }
+
+    #[test]
+    fn deserialize_char_from_two_char_list_message() {
+        let err = deserialize::<_, char>(|data| data.parse_add_char_list("ab")).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Expected CharList of length 1 for char, found length 2"
+        );
+    }
+
+    #[test]
+    fn deserialize_char_from_empty_char_list() {
+        assert_fails::<_, char>(|data| data.parse_add_char_list(""));
+    }
+
+    #[test]
+    fn deserialize_char_from_invalid_code_point() {
+        assert_fails::<_, char>(|data| data.add_number(SimpleNumber::Integer(0xD800)));
+    }
+
+    #[test]
+    fn deserialize_char_from_negative_code_point() {
+        let err = deserialize::<_, char>(|data| data.add_number(SimpleNumber::Integer(-159)))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "-159 is not a valid char code point");
+    }
+
+    #[test]
+    fn deserialize_char_from_fractional_code_point() {
+        let err =
+            deserialize::<_, char>(|data| data.add_number(SimpleNumber::Float(97.5))).unwrap_err();
+
+        assert_eq!(err.to_string(), "97.5 is not a valid char code point");
+    }
+
     // cannot currently be implemented
     // #[test]
     // fn deserialize_str() {
@@ -1011,6 +2411,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_string_from_symbol() {
+        assert_deserializes(|data| data.parse_add_symbol("abcd"), String::from("abcd"));
+    }
+
+    fn strict_char_list() -> GarnishDeserializationOptions {
+        GarnishDeserializationOptions::new()
+            .symbol_string_behavior(crate::SymbolStringBehavior::StrictCharList)
+    }
+
+    #[test]
+    fn deserialize_string_from_symbol_strict() {
+        let err = deserialize_with_options::<String>(strict_char_list(), |data| {
+            data.parse_add_symbol("abcd").unwrap()
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Expected CharList, Concatenation or Slice, found Symbol"
+        );
+    }
+
+    #[test]
+    fn deserialize_string_from_char_list_strict() {
+        let s = deserialize_with_options::<String>(strict_char_list(), |data| {
+            data.parse_add_char_list("abcd").unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(s, "abcd");
+    }
+
+    #[test]
+    fn deserialize_struct_strict_char_list_field_names() {
+        let value = deserialize_with_options::<SomeStruct>(strict_char_list(), |data| {
+            add_some_struct(data).unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(
+            value,
+            SomeStruct {
+                one: 100,
+                two: 200,
+                three: 300
+            }
+        );
+    }
+
     #[test]
     fn deserialize_string_from_slice() {
         assert_deserializes(
@@ -1026,6 +2476,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_string_from_byte_list() {
+        let mut data = SimpleGarnishData::new();
+        let addr = data.parse_add_byte_list("abcd").unwrap();
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().byte_list_as_string(true),
+        );
+
+        let s = String::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(s, "abcd");
+    }
+
+    #[test]
+    fn deserialize_string_from_byte_list_invalid_utf8() {
+        let mut data = SimpleGarnishData::new();
+        data.start_byte_list().unwrap();
+        data.add_to_byte_list(0xff).unwrap();
+        data.add_to_byte_list(0xfe).unwrap();
+        let addr = data.end_byte_list().unwrap();
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().byte_list_as_string(true),
+        );
+
+        let err = String::deserialize(&mut deserializer).unwrap_err();
+
+        assert!(err.to_string().starts_with("ByteList is not valid UTF-8"));
+    }
+
+    #[test]
+    fn deserialize_string_from_byte_list_without_option() {
+        assert_fails::<_, String>(|data| data.parse_add_byte_list("abcd"));
+    }
+
     // cannot currently be implemented
     // #[test]
     // fn deserialize_bytes() {
@@ -1150,6 +2638,57 @@ mod tests {
         );
     }
 
+    fn add_list_concatenation(data: &mut SimpleGarnishData) -> usize {
+        let list = add_number_list(data, &[1, 2, 3]);
+        let num = data.add_number(SimpleNumber::Integer(4)).unwrap();
+        data.add_concatenation(list, num).unwrap()
+    }
+
+    #[test]
+    fn deserialize_seq_from_concatenation_with_list() {
+        let value: GarnishValueTree =
+            deserialize_with_options(GarnishDeserializationOptions::new(), add_list_concatenation)
+                .unwrap();
+
+        assert_eq!(
+            value,
+            GarnishValueTree::List(vec![
+                GarnishValueTree::List(vec![
+                    GarnishValueTree::Integer(1),
+                    GarnishValueTree::Integer(2),
+                    GarnishValueTree::Integer(3),
+                ]),
+                GarnishValueTree::Integer(4),
+            ])
+        );
+    }
+
+    #[test]
+    fn deserialize_seq_from_concatenation_flattening_lists() {
+        let options = GarnishDeserializationOptions::new().flatten_lists_in_concatenation(true);
+
+        let value: Vec<i32> = deserialize_with_options(options, add_list_concatenation).unwrap();
+
+        assert_eq!(value, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn deserialize_large_seq_from_concatenation() {
+        let mut data = SimpleGarnishData::new();
+
+        let expected: Vec<i32> = (0..1000).collect();
+        let mut cat = data.add_number(SimpleNumber::Integer(0)).unwrap();
+        for n in expected.iter().skip(1) {
+            let num = data.add_number(SimpleNumber::Integer(*n)).unwrap();
+            cat = data.add_concatenation(cat, num).unwrap();
+        }
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, cat);
+        let value = Vec::<i32>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, expected);
+    }
+
     #[test]
     fn deserialize_seq_from_concatenation_slice() {
         assert_deserializes(
@@ -1248,6 +2787,350 @@ mod tests {
         );
     }
 
+    fn add_number_list(data: &mut SimpleGarnishData, numbers: &[i32]) -> usize {
+        let addrs: Vec<usize> = numbers
+            .iter()
+            .map(|n| data.add_number(SimpleNumber::Integer(*n)).unwrap())
+            .collect();
+
+        data.start_list(addrs.len()).unwrap();
+        for addr in addrs {
+            data.add_to_list(addr, false).unwrap();
+        }
+        data.end_list().unwrap()
+    }
+
+    fn add_list(data: &mut SimpleGarnishData, items: &[usize]) -> usize {
+        data.start_list(items.len()).unwrap();
+        for item in items {
+            data.add_to_list(*item, false).unwrap();
+        }
+        data.end_list().unwrap()
+    }
+
+    fn add_nested_list(data: &mut SimpleGarnishData, depth: usize) -> usize {
+        let mut value = data.add_number(SimpleNumber::Integer(1)).unwrap();
+        for _ in 0..depth {
+            value = add_list(data, &[value]);
+        }
+        value
+    }
+
+    #[test]
+    fn deserialize_nested_list_beyond_max_depth() {
+        let result: Result<GarnishValueTree, _> =
+            deserialize_with_options(GarnishDeserializationOptions::new(), |data| {
+                add_nested_list(data, 500)
+            });
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some(&"Maximum nesting depth of 128 exceeded.".to_string())
+        );
+    }
+
+    #[test]
+    fn deserialize_nested_list_at_max_depth() {
+        let options = GarnishDeserializationOptions::new().max_depth(10);
+
+        // 9 lists and the number make 10 nested values
+        let result: Result<GarnishValueTree, _> =
+            deserialize_with_options(options, |data| add_nested_list(data, 9));
+        assert!(result.is_ok());
+
+        let options = GarnishDeserializationOptions::new().max_depth(10);
+        let result: Result<GarnishValueTree, _> =
+            deserialize_with_options(options, |data| add_nested_list(data, 10));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_map_of_number_keys_to_lists() {
+        use std::collections::HashMap;
+
+        let mut data = SimpleGarnishData::new();
+
+        let mut pairs = vec![];
+        for (key, values) in [(1, vec!["a", "b"]), (-20, vec![]), (300, vec!["c"])] {
+            let items: Vec<usize> = values
+                .iter()
+                .map(|v| data.parse_add_char_list(v).unwrap())
+                .collect();
+            let list = add_list(&mut data, &items);
+            let key = data.add_number(SimpleNumber::Integer(key)).unwrap();
+            pairs.push(data.add_pair((key, list)).unwrap());
+        }
+
+        data.start_list(pairs.len()).unwrap();
+        for pair in pairs {
+            data.add_to_list(pair, true).unwrap();
+        }
+        let map = data.end_list().unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, map);
+        let value = HashMap::<i64, Vec<String>>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(
+            value,
+            HashMap::from([
+                (1, vec!["a".to_string(), "b".to_string()]),
+                (-20, vec![]),
+                (300, vec!["c".to_string()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn deserialize_tuple_of_collections() {
+        use std::collections::HashMap;
+
+        let mut data = SimpleGarnishData::new();
+
+        let numbers = add_number_list(&mut data, &[1, 2, 3]);
+
+        let k1 = data.parse_add_symbol("one").unwrap();
+        let v1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+        let k2 = data.parse_add_symbol("two").unwrap();
+        let v2 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+        let map = add_association_list(&mut data, &[(k1, v1), (k2, v2)]);
+
+        let s = data.parse_add_char_list("abcd").unwrap();
+        let tuple = add_list(&mut data, &[numbers, map, s]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, tuple);
+        let value =
+            <(Vec<i32>, HashMap<String, i32>, String)>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(
+            value,
+            (
+                vec![1, 2, 3],
+                HashMap::from([(String::from("one"), 100), (String::from("two"), 200)]),
+                String::from("abcd")
+            )
+        );
+        assert_eq!(deserializer.value_stack, vec![tuple]);
+    }
+
+    #[test]
+    fn deserialize_large_seq() {
+        let mut data = SimpleGarnishData::new();
+
+        let expected: Vec<i32> = (0..1000).collect();
+        let list = add_number_list(&mut data, &expected);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, list);
+        let value = Vec::<i32>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn deserialize_very_large_seq_in_order() {
+        let mut data = SimpleGarnishData::new();
+
+        let expected: Vec<i32> = (0..100_000).collect();
+        let list = add_number_list(&mut data, &expected);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, list);
+        let value = Vec::<i32>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn seq_size_hint_is_remaining_items() {
+        struct HintVisitor;
+
+        impl<'de> Visitor<'de> for HintVisitor {
+            type Value = Vec<Option<usize>>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut hints = vec![seq.size_hint()];
+                while seq.next_element::<i32>()?.is_some() {
+                    hints.push(seq.size_hint());
+                }
+                Ok(hints)
+            }
+        }
+
+        let mut data = SimpleGarnishData::new();
+        let list = add_number_list(&mut data, &[10, 20, 30]);
+
+        let deserializer = GarnishDataDeserializer::new_for_value(&mut data, list);
+        let hints = deserializer.deserialize_seq(HintVisitor).unwrap();
+
+        assert_eq!(hints, vec![Some(3), Some(2), Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn deserialize_seq_of_seq_of_seq() {
+        let mut data = SimpleGarnishData::new();
+
+        let list1 = add_number_list(&mut data, &[100, 200]);
+        let list2 = add_number_list(&mut data, &[300]);
+        let list3 = add_number_list(&mut data, &[]);
+        let list4 = add_number_list(&mut data, &[400, 500, 600]);
+
+        let middle1 = add_list(&mut data, &[list1, list2]);
+        let middle2 = add_list(&mut data, &[list3, list4]);
+        let middle3 = add_list(&mut data, &[]);
+
+        let outer = add_list(&mut data, &[middle1, middle2, middle3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, outer);
+        let value = Vec::<Vec<Vec<i32>>>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(
+            value,
+            vec![
+                vec![vec![100, 200], vec![300]],
+                vec![vec![], vec![400, 500, 600]],
+                vec![]
+            ]
+        );
+        // only the root value remains
+        assert_eq!(deserializer.value_stack, vec![outer]);
+    }
+
+    #[test]
+    fn deserialize_after_set_current() {
+        let mut data = SimpleGarnishData::new();
+        let number = data.add_number(SimpleNumber::Integer(100)).unwrap();
+        let list = add_number_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, number);
+        assert_eq!(i32::deserialize(&mut deserializer).unwrap(), 100);
+
+        deserializer.set_current(list).unwrap();
+        assert_eq!(
+            Vec::<i32>::deserialize(&mut deserializer).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(deserializer.value_stack, vec![list]);
+    }
+
+    #[test]
+    fn peek_current_list() {
+        let mut data = SimpleGarnishData::new();
+        let list = add_number_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, list);
+
+        assert_eq!(deserializer.current_addr(), Some(list));
+        assert_eq!(deserializer.current_type().unwrap(), GarnishDataType::List);
+        assert_eq!(
+            Vec::<i32>::deserialize(&mut deserializer).unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn peek_without_value() {
+        let mut data = SimpleGarnishData::new();
+        let number = data.add_number(SimpleNumber::Integer(100)).unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, number);
+        deserializer.value_stack.clear();
+
+        assert_eq!(deserializer.current_addr(), None);
+        assert!(deserializer.current_type().is_err());
+    }
+
+    #[test]
+    fn deserialize_at_addresses_in_sequence() {
+        let mut data = SimpleGarnishData::new();
+        let number = data.add_number(SimpleNumber::Integer(100)).unwrap();
+        let list = add_number_list(&mut data, &[1, 2, 3]);
+        let text = data.parse_add_char_list("\"text\"").unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, text);
+
+        assert_eq!(deserializer.deserialize_at::<i32>(number).unwrap(), 100);
+        assert_eq!(
+            deserializer.deserialize_at::<Vec<i32>>(list).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(deserializer.value_stack, vec![text]);
+    }
+
+    #[test]
+    fn deserialize_at_restores_stack_on_error() {
+        let mut data = SimpleGarnishData::new();
+        let number = data.add_number(SimpleNumber::Integer(100)).unwrap();
+        let list = add_number_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, number);
+
+        assert!(deserializer.deserialize_at::<Vec<String>>(list).is_err());
+        assert_eq!(deserializer.value_stack, vec![number]);
+        assert!(deserializer.path.is_empty());
+        assert_eq!(i32::deserialize(&mut deserializer).unwrap(), 100);
+    }
+
+    #[test]
+    fn set_current_without_value_fails() {
+        let mut data = SimpleGarnishData::new();
+        let number = data.add_number(SimpleNumber::Integer(100)).unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, number);
+        deserializer.value_stack.clear();
+
+        assert!(deserializer.set_current(number).is_err());
+        assert!(deserializer.value_stack.is_empty());
+    }
+
+    #[test]
+    fn deserialize_list_of_single_item_lists_as_one_tuples() {
+        let result: Vec<(i32,)> =
+            deserialize_with_options(GarnishDeserializationOptions::new(), |data| {
+                let items: Vec<usize> = [100, 200, 300]
+                    .iter()
+                    .map(|n| {
+                        let value = data.add_number(SimpleNumber::Integer(*n)).unwrap();
+                        add_list(data, &[value])
+                    })
+                    .collect();
+                add_list(data, &items)
+            })
+            .unwrap();
+
+        assert_eq!(result, vec![(100,), (200,), (300,)]);
+    }
+
+    #[test]
+    fn deserialize_one_tuple_keeps_inner_list() {
+        // single item list holding a list, the inner list is the tuple's item rather than its contents
+        let result: Vec<(Vec<i32>,)> =
+            deserialize_with_options(GarnishDeserializationOptions::new(), |data| {
+                let inner = add_number_list(data, &[1, 2]);
+                let tuple = add_list(data, &[inner]);
+                add_list(data, &[tuple])
+            })
+            .unwrap();
+
+        assert_eq!(result, vec![(vec![1, 2],)]);
+    }
+
+    #[test]
+    fn deserialize_one_tuple_from_longer_list_fails() {
+        let result: Result<Vec<(i32,)>, _> =
+            deserialize_with_options(GarnishDeserializationOptions::new(), |data| {
+                let tuple = add_number_list(data, &[1, 2]);
+                add_list(data, &[tuple])
+            });
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn deserialize_tuple() {
         assert_deserializes(
@@ -1265,6 +3148,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_tuple_from_empty_list() {
+        let e = deserialize::<_, (i32, i32, i32)>(|data| {
+            data.start_list(0).unwrap();
+            data.end_list()
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            e.message().unwrap(),
+            "Expected tuple of length 3, found empty list."
+        );
+    }
+
+    #[test]
+    fn deserialize_vec_from_empty_list() {
+        assert_deserializes(
+            |data| {
+                data.start_list(0).unwrap();
+                data.end_list()
+            },
+            Vec::<i32>::new(),
+        );
+    }
+
     #[derive(Debug, PartialEq, Deserialize)]
     struct SomeNumbers(i32, i32, i32);
 
@@ -1313,6 +3221,48 @@ mod tests {
         });
     }
 
+    #[test]
+    fn deserialize_tuple_from_pair() {
+        assert_deserializes(
+            |data| {
+                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let num2 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                let num3 = data.add_number(SimpleNumber::Integer(300)).unwrap();
+                data.start_list(3).unwrap();
+                data.add_to_list(num1, false).unwrap();
+                data.add_to_list(num2, false).unwrap();
+                data.add_to_list(num3, false).unwrap();
+                let list = data.end_list().unwrap();
+
+                let s = data.parse_add_char_list("abcd").unwrap();
+
+                data.add_pair((s, list))
+            },
+            (String::from("abcd"), vec![100, 200, 300]),
+        );
+    }
+
+    #[test]
+    fn deserialize_standalone_pair() {
+        assert_deserializes(
+            |data| {
+                let sym = data.parse_add_symbol("one").unwrap();
+                let num = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                data.add_pair((sym, num))
+            },
+            (String::from("one"), 100),
+        );
+    }
+
+    #[test]
+    fn deserialize_pair_into_three_tuple_fails() {
+        assert_fails::<_, (String, i32, i32)>(|data| {
+            let sym = data.parse_add_symbol("one").unwrap();
+            let num = data.add_number(SimpleNumber::Integer(100)).unwrap();
+            data.add_pair((sym, num))
+        });
+    }
+
     #[test]
     fn deserialize_map() {
         let mut expected = HashMap::new();
@@ -1509,55 +3459,317 @@ mod tests {
         );
     }
 
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct OneAndTwo {
+        one: i32,
+        two: i32,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct StrictOneAndTwo {
+        one: i32,
+        two: i32,
+    }
+
     #[test]
-    fn deserialize_struct_from_list_slice() {
+    fn deserialize_struct_ignores_extra_pair() {
+        assert_deserializes(add_some_struct, OneAndTwo { one: 100, two: 200 });
+    }
+
+    #[test]
+    fn deserialize_struct_deny_unknown_fields_rejects_extra_pair() {
+        let e = deserialize::<_, StrictOneAndTwo>(add_some_struct).unwrap_err();
+
+        assert!(e.message().unwrap().contains("unknown field `three`"));
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SkippedFieldStruct {
+        one: i32,
+        #[serde(skip_deserializing, default)]
+        skipped: String,
+        two: i32,
+    }
+
+    #[test]
+    fn deserialize_struct_with_skipped_field() {
         assert_deserializes(
             |data| {
-                let sym1 = data.parse_add_symbol("zero").unwrap();
-                let num1 = data.add_number(SimpleNumber::Integer(0)).unwrap();
-                let pair1 = data.add_pair((sym1, num1)).unwrap();
-
                 let sym1 = data.parse_add_symbol("one").unwrap();
                 let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
-                let pair2 = data.add_pair((sym1, num1)).unwrap();
-
-                let sym1 = data.parse_add_symbol("two").unwrap();
-                let num1 = data.add_number(SimpleNumber::Integer(200)).unwrap();
-                let pair3 = data.add_pair((sym1, num1)).unwrap();
+                let pair1 = data.add_pair((sym1, num1)).unwrap();
 
-                let sym1 = data.parse_add_symbol("three").unwrap();
-                let num1 = data.add_number(SimpleNumber::Integer(300)).unwrap();
-                let pair4 = data.add_pair((sym1, num1)).unwrap();
+                let sym2 = data.parse_add_symbol("two").unwrap();
+                let num2 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                let pair2 = data.add_pair((sym2, num2)).unwrap();
 
-                data.start_list(3).unwrap();
+                data.start_list(2).unwrap();
                 data.add_to_list(pair1, true).unwrap();
                 data.add_to_list(pair2, true).unwrap();
-                data.add_to_list(pair3, true).unwrap();
-                data.add_to_list(pair4, true).unwrap();
-                let list = data.end_list().unwrap();
-
-                let start = data.add_number(SimpleNumber::Integer(1)).unwrap();
-                let end = data.add_number(SimpleNumber::Integer(3)).unwrap();
-                let range = data.add_range(start, end).unwrap();
-
-                data.add_slice(list, range)
+                data.end_list()
             },
-            SomeStruct {
+            SkippedFieldStruct {
                 one: 100,
+                skipped: String::new(),
                 two: 200,
-                three: 300,
             },
         );
     }
 
     #[test]
-    fn deserialize_struct_from_concatenation() {
+    fn deserialize_struct_with_skipped_field_present() {
         assert_deserializes(
-            add_some_struct_as_concat,
-            SomeStruct {
+            |data| {
+                let sym1 = data.parse_add_symbol("one").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let pair1 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym2 = data.parse_add_symbol("skipped").unwrap();
+                let s = data.parse_add_char_list("ignored").unwrap();
+                let pair2 = data.add_pair((sym2, s)).unwrap();
+
+                let sym3 = data.parse_add_symbol("two").unwrap();
+                let num3 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                let pair3 = data.add_pair((sym3, num3)).unwrap();
+
+                data.start_list(3).unwrap();
+                data.add_to_list(pair1, true).unwrap();
+                data.add_to_list(pair2, true).unwrap();
+                data.add_to_list(pair3, true).unwrap();
+                data.end_list()
+            },
+            SkippedFieldStruct {
                 one: 100,
+                skipped: String::new(),
                 two: 200,
-                three: 300,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_seq_deserializer_from_value_refs() {
+        use crate::deserializer::GarnishValueRef;
+        use crate::GarnishSerializationError;
+        use serde::de::value::SeqDeserializer;
+
+        let mut data = SimpleGarnishData::new();
+        let addrs = [
+            data.add_number(SimpleNumber::Integer(10)).unwrap(),
+            data.add_number(SimpleNumber::Integer(20)).unwrap(),
+            data.add_number(SimpleNumber::Integer(30)).unwrap(),
+        ];
+
+        let de = SeqDeserializer::<_, GarnishSerializationError<SimpleGarnishData>>::new(
+            addrs.iter().map(|addr| GarnishValueRef::new(&data, *addr)),
+        );
+
+        let values = Vec::<i32>::deserialize(de).unwrap();
+        assert_eq!(values, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn deserialize_from_value_mut_without_string_conversions() {
+        use crate::deserializer::GarnishValueMut;
+        use crate::test_data::UncachedGarnishData;
+        use serde::de::IntoDeserializer;
+
+        let mut data = UncachedGarnishData::new();
+        let one = data.add_number(SimpleNumber::Integer(10)).unwrap();
+        let two = data.add_number(SimpleNumber::Integer(20)).unwrap();
+        data.start_list(2).unwrap();
+        data.add_to_list(one, false).unwrap();
+        data.add_to_list(two, false).unwrap();
+        let list = data.end_list().unwrap();
+
+        let value =
+            Vec::<i32>::deserialize(GarnishValueMut::new(&mut data, list).into_deserializer())
+                .unwrap();
+        assert_eq!(value, vec![10, 20]);
+    }
+
+    #[test]
+    fn deserialize_struct_from_value_ref() {
+        use crate::deserializer::GarnishValueRef;
+        use serde::de::IntoDeserializer;
+
+        let mut data = SimpleGarnishData::new();
+        let addr = add_some_struct(&mut data).unwrap();
+
+        let value =
+            SomeStruct::deserialize(GarnishValueRef::new(&data, addr).into_deserializer()).unwrap();
+        assert_eq!(
+            value,
+            SomeStruct {
+                one: 100,
+                two: 200,
+                three: 300
+            }
+        );
+    }
+
+    fn add_association_list(data: &mut SimpleGarnishData, pairs: &[(usize, usize)]) -> usize {
+        let pairs = pairs
+            .iter()
+            .map(|pair| data.add_pair(*pair).unwrap())
+            .collect::<Vec<usize>>();
+
+        data.start_list(pairs.len()).unwrap();
+        for pair in pairs {
+            data.add_to_list(pair, true).unwrap();
+        }
+        data.end_list().unwrap()
+    }
+
+    #[test]
+    fn deserialize_map_with_number_keys() {
+        use std::collections::BTreeMap;
+
+        assert_deserializes(
+            |data| {
+                let k1 = data.add_number(SimpleNumber::Integer(-5)).unwrap();
+                let v1 = data.parse_add_char_list("minus five").unwrap();
+                let k2 = data.add_number(SimpleNumber::Integer(10)).unwrap();
+                let v2 = data.parse_add_char_list("ten").unwrap();
+
+                Ok(add_association_list(data, &[(k1, v1), (k2, v2)]))
+            },
+            BTreeMap::from([(-5, String::from("minus five")), (10, String::from("ten"))]),
+        );
+    }
+
+    #[test]
+    fn deserialize_map_with_bool_keys() {
+        use std::collections::BTreeMap;
+
+        assert_deserializes(
+            |data| {
+                let k1 = data.add_true().unwrap();
+                let v1 = data.add_number(SimpleNumber::Integer(1)).unwrap();
+                let k2 = data.add_false().unwrap();
+                let v2 = data.add_number(SimpleNumber::Integer(0)).unwrap();
+
+                Ok(add_association_list(data, &[(k1, v1), (k2, v2)]))
+            },
+            BTreeMap::from([(true, 1), (false, 0)]),
+        );
+    }
+
+    #[test]
+    fn deserialize_map_with_number_keys_as_string_keys_fails() {
+        use std::collections::BTreeMap;
+
+        assert_fails::<_, BTreeMap<String, i32>>(|data| {
+            let k = data.add_number(SimpleNumber::Integer(10)).unwrap();
+            let v = data.add_number(SimpleNumber::Integer(10)).unwrap();
+
+            Ok(add_association_list(data, &[(k, v)]))
+        });
+    }
+
+    fn add_struct_starting_at(data: &mut SimpleGarnishData, start: i32) -> usize {
+        let mut pairs = vec![];
+        for (i, name) in ["one", "two", "three"].iter().enumerate() {
+            let sym = data.parse_add_symbol(name).unwrap();
+            let num = data
+                .add_number(SimpleNumber::Integer(start + i as i32))
+                .unwrap();
+            pairs.push(data.add_pair((sym, num)).unwrap());
+        }
+
+        data.start_list(3).unwrap();
+        for pair in pairs {
+            data.add_to_list(pair, true).unwrap();
+        }
+        data.end_list().unwrap()
+    }
+
+    #[test]
+    fn deserialize_values_iter() {
+        let mut data = SimpleGarnishData::new();
+        let addrs = vec![
+            add_struct_starting_at(&mut data, 10),
+            add_struct_starting_at(&mut data, 20),
+            add_struct_starting_at(&mut data, 30),
+        ];
+
+        let mut deserializer = GarnishDataDeserializer::new(&mut data);
+        let values = deserializer
+            .values_iter(addrs)
+            .collect::<Result<Vec<SomeStruct>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                SomeStruct {
+                    one: 10,
+                    two: 11,
+                    three: 12
+                },
+                SomeStruct {
+                    one: 20,
+                    two: 21,
+                    three: 22
+                },
+                SomeStruct {
+                    one: 30,
+                    two: 31,
+                    three: 32
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_struct_from_list_slice() {
+        assert_deserializes(
+            |data| {
+                let sym1 = data.parse_add_symbol("zero").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(0)).unwrap();
+                let pair1 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym1 = data.parse_add_symbol("one").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let pair2 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym1 = data.parse_add_symbol("two").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                let pair3 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym1 = data.parse_add_symbol("three").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(300)).unwrap();
+                let pair4 = data.add_pair((sym1, num1)).unwrap();
+
+                data.start_list(3).unwrap();
+                data.add_to_list(pair1, true).unwrap();
+                data.add_to_list(pair2, true).unwrap();
+                data.add_to_list(pair3, true).unwrap();
+                data.add_to_list(pair4, true).unwrap();
+                let list = data.end_list().unwrap();
+
+                let start = data.add_number(SimpleNumber::Integer(1)).unwrap();
+                let end = data.add_number(SimpleNumber::Integer(3)).unwrap();
+                let range = data.add_range(start, end).unwrap();
+
+                data.add_slice(list, range)
+            },
+            SomeStruct {
+                one: 100,
+                two: 200,
+                three: 300,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_struct_from_concatenation() {
+        assert_deserializes(
+            add_some_struct_as_concat,
+            SomeStruct {
+                one: 100,
+                two: 200,
+                three: 300,
             },
         );
     }
@@ -1600,7 +3812,164 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_exclusive_range_with_maximum_end_fails() {
+        use crate::test_data::{WideGarnishData, WideNumber};
+
+        let mut data = WideGarnishData::new();
+        let start = data.add_number(WideNumber::Integer(0)).unwrap();
+        let end = data
+            .add_number(WideNumber::Integer(i128::from(i64::MAX)))
+            .unwrap();
+        let range = data.add_range(start, end).unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, range);
+        let err = std::ops::Range::<i64>::deserialize(&mut deserializer).unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            Some(&format!(
+                "Range end {} has no next integer to convert to an exclusive Range.",
+                i64::MAX
+            ))
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SomeSkippingStruct {
+        one: i32,
+        #[serde(skip_deserializing)]
+        two: i32,
+    }
+
+    #[test]
+    fn deserialize_struct_ignoring_fields() {
+        assert_deserializes(
+            |data| {
+                let sym1 = data.parse_add_symbol("one").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let pair1 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym1 = data.parse_add_symbol("two").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                let pair2 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym1 = data.parse_add_symbol("three").unwrap();
+                let list = add_number_list(data, &[300, 400]);
+                let pair3 = data.add_pair((sym1, list)).unwrap();
+
+                let sym1 = data.parse_add_symbol("four").unwrap();
+                let chars = data.parse_add_char_list("\"four\"").unwrap();
+                let pair4 = data.add_pair((sym1, chars)).unwrap();
+
+                data.start_list(4).unwrap();
+                data.add_to_list(pair1, true).unwrap();
+                data.add_to_list(pair2, true).unwrap();
+                data.add_to_list(pair3, true).unwrap();
+                data.add_to_list(pair4, true).unwrap();
+                data.end_list()
+            },
+            SomeSkippingStruct { one: 100, two: 0 },
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SomeAliasedStruct {
+        #[serde(alias = "first", alias = "uno")]
+        one: i32,
+        two: i32,
+    }
+
+    #[test]
+    fn deserialize_struct_with_alias() {
+        assert_deserializes(
+            |data| {
+                let sym1 = data.parse_add_symbol("uno").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let pair1 = data.add_pair((sym1, num1)).unwrap();
+
+                let sym1 = data.parse_add_symbol("two").unwrap();
+                let num1 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                let pair2 = data.add_pair((sym1, num1)).unwrap();
+
+                data.start_list(2).unwrap();
+                data.add_to_list(pair1, true).unwrap();
+                data.add_to_list(pair2, true).unwrap();
+                data.end_list()
+            },
+            SomeAliasedStruct { one: 100, two: 200 },
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SomeServer {
+        port: u16,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SomeConfig {
+        servers: Vec<SomeServer>,
+    }
+
+    #[test]
+    fn deserialize_error_includes_path() {
+        let mut data = SimpleGarnishData::new();
+
+        let port = data.parse_add_symbol("port").unwrap();
+        let value = data.add_number(SimpleNumber::Integer(80)).unwrap();
+        let pair = data.add_pair((port, value)).unwrap();
+        let server1 = add_list(&mut data, &[pair]);
+
+        let value = data.parse_add_char_list("\"eighty\"").unwrap();
+        let pair = data.add_pair((port, value)).unwrap();
+        let server2 = add_list(&mut data, &[pair]);
+
+        let servers = add_list(&mut data, &[server1, server2]);
+        let sym = data.parse_add_symbol("servers").unwrap();
+        let pair = data.add_pair((sym, servers)).unwrap();
+        let config = add_list(&mut data, &[pair]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, config);
+        let err = SomeConfig::deserialize(&mut deserializer).unwrap_err();
+
+        assert_eq!(err.path(), Some(&"servers[1].port".to_string()));
+        assert!(err.to_string().starts_with("at servers[1].port: "));
+        assert!(deserializer.path.is_empty());
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct SomeAddress {
+        zip: u32,
+    }
+
     #[derive(Debug, PartialEq, Deserialize)]
+    struct SomePerson {
+        address: SomeAddress,
+    }
+
+    #[test]
+    fn deserialize_error_includes_nested_struct_path() {
+        let mut data = SimpleGarnishData::new();
+
+        let zip = data.parse_add_symbol("zip").unwrap();
+        let value = data.parse_add_char_list("\"12345\"").unwrap();
+        let pair = data.add_pair((zip, value)).unwrap();
+        let address = add_list(&mut data, &[pair]);
+
+        let sym = data.parse_add_symbol("address").unwrap();
+        let pair = data.add_pair((sym, address)).unwrap();
+        let person = add_list(&mut data, &[pair]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, person);
+        let err = SomePerson::deserialize(&mut deserializer).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "at address.zip: Expected Number, found CharList"
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
     enum SomeEnum {
         SomeUnitVariant,
         SomeNewTypeVariant(i32),
@@ -1608,6 +3977,32 @@ mod tests {
         SomeStructVariant { one: i32, two: i32, three: i32 },
     }
 
+    #[test]
+    fn deserialize_many_variants_within_max_depth() {
+        let options = GarnishDeserializationOptions::new().max_depth(4);
+
+        let result: Vec<SomeEnum> = deserialize_with_options(options, |data| {
+            let items: Vec<usize> = (0..200)
+                .map(|n| {
+                    let value = data.add_number(SimpleNumber::Integer(n)).unwrap();
+                    let variant = data
+                        .parse_add_symbol("SomeEnum::SomeNewTypeVariant")
+                        .unwrap();
+                    add_list(data, &[variant, value])
+                })
+                .collect();
+            add_list(data, &items)
+        })
+        .unwrap();
+
+        assert_eq!(
+            result,
+            (0..200)
+                .map(SomeEnum::SomeNewTypeVariant)
+                .collect::<Vec<SomeEnum>>()
+        );
+    }
+
     #[test]
     fn deserialize_unit_variant() {
         assert_deserializes(
@@ -1616,6 +4011,45 @@ mod tests {
         );
     }
 
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum RenamedEnum {
+        #[serde(rename = "Renamed::Variant")]
+        Variant,
+        Other,
+    }
+
+    #[test]
+    fn deserialize_unit_variant_from_short_name() {
+        assert_deserializes(
+            |data| data.parse_add_symbol("SomeUnitVariant"),
+            SomeEnum::SomeUnitVariant,
+        );
+    }
+
+    #[test]
+    fn deserialize_unit_variant_from_path_name() {
+        assert_deserializes(
+            |data| data.parse_add_symbol("crate::SomeEnum::SomeUnitVariant"),
+            SomeEnum::SomeUnitVariant,
+        );
+    }
+
+    #[test]
+    fn deserialize_unit_variant_renamed_with_separator() {
+        assert_deserializes(
+            |data| data.parse_add_symbol("Renamed::Variant"),
+            RenamedEnum::Variant,
+        );
+        assert_deserializes(
+            |data| data.parse_add_symbol("RenamedEnum::Other"),
+            RenamedEnum::Other,
+        );
+        assert_deserializes(
+            |data| data.parse_add_symbol("RenamedEnum::Renamed::Variant"),
+            RenamedEnum::Variant,
+        );
+    }
+
     #[test]
     fn deserialize_newtype_variant() {
         assert_deserializes(
@@ -1636,42 +4070,356 @@ mod tests {
     }
 
     #[test]
-    fn deserialize_tuple_variant() {
+    fn deserialize_pair_newtype_variant() {
         assert_deserializes(
             |data| {
-                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
-                let num2 = data.add_number(SimpleNumber::Integer(200)).unwrap();
-                data.start_list(2).unwrap();
-                data.add_to_list(num1, false).unwrap();
-                data.add_to_list(num2, false).unwrap();
-                let value = data.end_list().unwrap();
-
-                let variant = data.parse_add_symbol("SomeEnum::SomeTupleVariant").unwrap();
+                let value = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let variant = data
+                    .parse_add_symbol("SomeEnum::SomeNewTypeVariant")
+                    .unwrap();
 
-                data.start_list(2).unwrap();
-                data.add_to_list(variant, false).unwrap();
-                data.add_to_list(value, false).unwrap();
-                data.end_list()
+                data.add_pair((variant, value))
             },
-            SomeEnum::SomeTupleVariant(100, 200),
+            SomeEnum::SomeNewTypeVariant(100),
         );
     }
 
     #[test]
-    fn deserialize_struct_variant() {
+    fn deserialize_short_name_pair_newtype_variant() {
         assert_deserializes(
             |data| {
-                let value = add_some_struct(data).unwrap();
-
-                let variant = data
-                    .parse_add_symbol("SomeEnum::SomeStructVariant")
-                    .unwrap();
+                let value = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let variant = data.parse_add_symbol("SomeNewTypeVariant").unwrap();
+
+                data.add_pair((variant, value))
+            },
+            SomeEnum::SomeNewTypeVariant(100),
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum LenientEnum {
+        Known,
+        Unknown(String),
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum OtherEnum {
+        Known,
+        #[serde(other)]
+        Other,
+    }
+
+    fn capture_unknown(fallback: &'static str) -> GarnishDeserializationOptions {
+        GarnishDeserializationOptions::new()
+            .unknown_variant_behavior(UnknownVariantBehavior::Capture(fallback))
+    }
+
+    #[test]
+    fn deserialize_unknown_variant_errors() {
+        let options = GarnishDeserializationOptions::new();
+        let err = deserialize_with_options::<LenientEnum>(options, |data| {
+            data.parse_add_symbol("Mystery").unwrap()
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("unknown variant `Mystery`"));
+    }
+
+    #[test]
+    fn deserialize_unknown_variant_captured() {
+        let v: LenientEnum = deserialize_with_options(capture_unknown("Unknown"), |data| {
+            data.parse_add_symbol("Mystery").unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(v, LenientEnum::Unknown("Mystery".to_string()));
+    }
+
+    #[test]
+    fn deserialize_unknown_variant_with_value_captured() {
+        let v: Vec<LenientEnum> = deserialize_with_options(capture_unknown("Unknown"), |data| {
+            let value = data.add_number(SimpleNumber::Integer(100)).unwrap();
+            let variant = data.parse_add_symbol("Mystery").unwrap();
+            let unknown = add_list(data, &[variant, value]);
+            let known = data.parse_add_symbol("LenientEnum::Known").unwrap();
+            add_list(data, &[unknown, known])
+        })
+        .unwrap();
+
+        assert_eq!(
+            v,
+            vec![
+                LenientEnum::Unknown("Mystery".to_string()),
+                LenientEnum::Known
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_unknown_variant_captured_by_other() {
+        let v: OtherEnum = deserialize_with_options(capture_unknown("Other"), |data| {
+            data.parse_add_symbol("OtherEnum::Mystery").unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(v, OtherEnum::Other);
+    }
+
+    #[test]
+    fn deserialize_known_variant_not_captured() {
+        let v: LenientEnum = deserialize_with_options(capture_unknown("Unknown"), |data| {
+            data.parse_add_symbol("LenientEnum::Known").unwrap()
+        })
+        .unwrap();
+
+        assert_eq!(v, LenientEnum::Known);
+    }
+
+    #[test]
+    fn deserialize_tuple_variant() {
+        assert_deserializes(
+            |data| {
+                let num1 = data.add_number(SimpleNumber::Integer(100)).unwrap();
+                let num2 = data.add_number(SimpleNumber::Integer(200)).unwrap();
+                data.start_list(2).unwrap();
+                data.add_to_list(num1, false).unwrap();
+                data.add_to_list(num2, false).unwrap();
+                let value = data.end_list().unwrap();
+
+                let variant = data.parse_add_symbol("SomeEnum::SomeTupleVariant").unwrap();
 
                 data.start_list(2).unwrap();
                 data.add_to_list(variant, false).unwrap();
                 data.add_to_list(value, false).unwrap();
                 data.end_list()
             },
+            SomeEnum::SomeTupleVariant(100, 200),
+        );
+    }
+
+    #[test]
+    fn deserialize_struct_variant() {
+        assert_deserializes(
+            |data| {
+                let value = add_some_struct(data).unwrap();
+
+                let variant = data
+                    .parse_add_symbol("SomeEnum::SomeStructVariant")
+                    .unwrap();
+
+                data.start_list(2).unwrap();
+                data.add_to_list(variant, false).unwrap();
+                data.add_to_list(value, false).unwrap();
+                data.end_list()
+            },
+            SomeEnum::SomeStructVariant {
+                one: 100,
+                two: 200,
+                three: 300,
+            },
+        );
+    }
+
+    fn assert_variant_round_trip(behavior: VariantNameBehavior, value: SomeEnum) {
+        assert_variant_round_trip_with_tag(behavior, TagPosition::First, value);
+    }
+
+    fn assert_variant_round_trip_with_tag(
+        behavior: VariantNameBehavior,
+        tag_position: TagPosition,
+        value: SomeEnum,
+    ) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .variant_name_behavior(behavior)
+                .type_tag_position(tag_position),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().variant_tag_position(tag_position),
+        );
+        assert_eq!(SomeEnum::deserialize(&mut deserializer).unwrap(), value);
+    }
+
+    #[test]
+    fn deserialize_struct_variant_from_concatenation() {
+        assert_deserializes(
+            |data| {
+                let value = add_some_struct_as_concat(data).unwrap();
+
+                let variant = data
+                    .parse_add_symbol("SomeEnum::SomeStructVariant")
+                    .unwrap();
+
+                data.start_list(2).unwrap();
+                data.add_to_list(variant, false).unwrap();
+                data.add_to_list(value, false).unwrap();
+                data.end_list()
+            },
+            SomeEnum::SomeStructVariant {
+                one: 100,
+                two: 200,
+                three: 300,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_short_name_unit_variant() {
+        assert_variant_round_trip(VariantNameBehavior::Short, SomeEnum::SomeUnitVariant);
+    }
+
+    #[test]
+    fn deserialize_short_name_newtype_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::Short,
+            SomeEnum::SomeNewTypeVariant(100),
+        );
+    }
+
+    #[test]
+    fn deserialize_short_name_tuple_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::Short,
+            SomeEnum::SomeTupleVariant(100, 200),
+        );
+    }
+
+    #[test]
+    fn deserialize_short_name_struct_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::Short,
+            SomeEnum::SomeStructVariant {
+                one: 100,
+                two: 200,
+                three: 300,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_short_name_string_unit_variant() {
+        assert_variant_round_trip(VariantNameBehavior::ShortString, SomeEnum::SomeUnitVariant);
+    }
+
+    #[test]
+    fn deserialize_full_name_string_unit_variant() {
+        assert_variant_round_trip(VariantNameBehavior::FullString, SomeEnum::SomeUnitVariant);
+    }
+
+    #[test]
+    fn deserialize_full_name_string_newtype_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::FullString,
+            SomeEnum::SomeNewTypeVariant(100),
+        );
+    }
+
+    #[test]
+    fn deserialize_char_list_tagged_unit_variant() {
+        let mut data = UncachedGarnishData::new();
+        data.start_char_list().unwrap();
+        for c in "SomeEnum::SomeUnitVariant".chars() {
+            data.add_to_char_list(c).unwrap();
+        }
+        let addr = data.end_char_list().unwrap();
+        let len = data.get_data_len();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, addr);
+        let value = SomeEnum::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, SomeEnum::SomeUnitVariant);
+        // tag is read without being copied to another char list
+        assert_eq!(data.get_data_len(), len);
+    }
+
+    #[test]
+    fn deserialize_char_list_tagged_newtype_variant() {
+        assert_deserializes(
+            |data| {
+                let variant = data.parse_add_char_list("\"SomeNewTypeVariant\"").unwrap();
+                let value = data.add_number(SimpleNumber::Integer(100)).unwrap();
+
+                data.start_list(2).unwrap();
+                data.add_to_list(variant, false).unwrap();
+                data.add_to_list(value, false).unwrap();
+                data.end_list()
+            },
+            SomeEnum::SomeNewTypeVariant(100),
+        );
+    }
+
+    #[test]
+    fn deserialize_index_unit_variant() {
+        assert_variant_round_trip(VariantNameBehavior::Index, SomeEnum::SomeUnitVariant);
+    }
+
+    #[test]
+    fn deserialize_index_newtype_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::Index,
+            SomeEnum::SomeNewTypeVariant(100),
+        );
+    }
+
+    #[test]
+    fn deserialize_index_tuple_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::Index,
+            SomeEnum::SomeTupleVariant(100, 200),
+        );
+    }
+
+    #[test]
+    fn deserialize_index_struct_variant() {
+        assert_variant_round_trip(
+            VariantNameBehavior::Index,
+            SomeEnum::SomeStructVariant {
+                one: 100,
+                two: 200,
+                three: 300,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_trailing_tag_unit_variant() {
+        assert_variant_round_trip_with_tag(
+            VariantNameBehavior::Full,
+            TagPosition::Last,
+            SomeEnum::SomeUnitVariant,
+        );
+    }
+
+    #[test]
+    fn deserialize_trailing_tag_newtype_variant() {
+        assert_variant_round_trip_with_tag(
+            VariantNameBehavior::Full,
+            TagPosition::Last,
+            SomeEnum::SomeNewTypeVariant(100),
+        );
+    }
+
+    #[test]
+    fn deserialize_trailing_tag_tuple_variant() {
+        assert_variant_round_trip_with_tag(
+            VariantNameBehavior::Full,
+            TagPosition::Last,
+            SomeEnum::SomeTupleVariant(100, 200),
+        );
+    }
+
+    #[test]
+    fn deserialize_trailing_tag_struct_variant() {
+        assert_variant_round_trip_with_tag(
+            VariantNameBehavior::Index,
+            TagPosition::Last,
             SomeEnum::SomeStructVariant {
                 one: 100,
                 two: 200,
@@ -1679,4 +4427,267 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn deserialize_trailing_tag_from_list_missing_value() {
+        let mut data = SimpleGarnishData::new();
+        let sym = data
+            .parse_add_symbol("SomeEnum::SomeNewTypeVariant")
+            .unwrap();
+        let list = add_list(&mut data, &[sym]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            list,
+            GarnishDeserializationOptions::new().variant_tag_position(TagPosition::Last),
+        );
+
+        assert!(SomeEnum::deserialize(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_trailing_tag_from_list_with_extra_values() {
+        let mut data = SimpleGarnishData::new();
+        let first = data.add_number(SimpleNumber::Integer(100)).unwrap();
+        let second = data.add_number(SimpleNumber::Integer(200)).unwrap();
+        let sym = data
+            .parse_add_symbol("SomeEnum::SomeNewTypeVariant")
+            .unwrap();
+        let list = add_list(&mut data, &[first, second, sym]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            list,
+            GarnishDeserializationOptions::new().variant_tag_position(TagPosition::Last),
+        );
+
+        let err = SomeEnum::deserialize(&mut deserializer).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected tagged list with the tag last to contain only a value and a tag, found length 3"
+        );
+    }
+
+    #[test]
+    fn deserialize_many_symbols_with_string_buffer() {
+        let mut data = SimpleGarnishData::new();
+        let names: Vec<String> = (0..1000).map(|i| format!("symbol_{}", i)).collect();
+        let symbols: Vec<usize> = names
+            .iter()
+            .map(|name| data.parse_add_symbol(name).unwrap())
+            .collect();
+        let list = add_list(&mut data, &symbols);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            list,
+            GarnishDeserializationOptions::new().reuse_string_buffer(true),
+        );
+        let value = Vec::<String>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, names);
+        assert!(deserializer.string_buffer.capacity() >= "symbol_999".len());
+    }
+
+    #[test]
+    fn deserialize_struct_and_variant_with_string_buffer() {
+        let mut data = SimpleGarnishData::new();
+        let value = add_some_struct(&mut data).unwrap();
+        let variant = data
+            .parse_add_symbol("SomeEnum::SomeStructVariant")
+            .unwrap();
+        let list = add_list(&mut data, &[variant, value]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            list,
+            GarnishDeserializationOptions::new().reuse_string_buffer(true),
+        );
+
+        assert_eq!(
+            SomeEnum::deserialize(&mut deserializer).unwrap(),
+            SomeEnum::SomeStructVariant {
+                one: 100,
+                two: 200,
+                three: 300,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_read_only() {
+        let mut data = SimpleGarnishData::new();
+        let struct_addr = add_some_struct(&mut data).unwrap();
+        let symbol_addr = data.parse_add_symbol("abcd").unwrap();
+        let variant_addr = data.parse_add_symbol("SomeEnum::SomeUnitVariant").unwrap();
+
+        let data = &data;
+        let mut struct_deserializer =
+            GarnishDataDeserializer::new_read_only_for_value(data, struct_addr);
+        let mut symbol_deserializer =
+            GarnishDataDeserializer::new_read_only_for_value(data, symbol_addr);
+        let mut variant_deserializer =
+            GarnishDataDeserializer::new_read_only_for_value(data, variant_addr);
+
+        assert_eq!(
+            SomeStruct::deserialize(&mut struct_deserializer).unwrap(),
+            SomeStruct {
+                one: 100,
+                two: 200,
+                three: 300,
+            }
+        );
+        assert_eq!(
+            String::deserialize(&mut symbol_deserializer).unwrap(),
+            "abcd".to_string()
+        );
+        assert_eq!(
+            SomeEnum::deserialize(&mut variant_deserializer).unwrap(),
+            SomeEnum::SomeUnitVariant
+        );
+    }
+
+    #[test]
+    fn deserialize_read_only_does_not_add_data() {
+        let mut data = SimpleGarnishData::new();
+        let addr = add_some_struct(&mut data).unwrap();
+        data.push_value_stack(addr).unwrap();
+        let len = data.get_data_len();
+
+        let mut deserializer = GarnishDataDeserializer::new_read_only(&data);
+        SomeStruct::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(data.get_data_len(), len);
+    }
+
+    #[test]
+    fn deserialize_borrowed_str() {
+        let mut data = SimpleGarnishData::new();
+        let addr = data.parse_add_char_list("abcd").unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_borrowing_for_value(&data, addr);
+        let value = <&str>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, "abcd");
+    }
+
+    #[test]
+    fn deserialize_struct_with_borrowed_str() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Named<'a> {
+            name: &'a str,
+        }
+
+        let mut data = SimpleGarnishData::new();
+        let key = data.parse_add_symbol("name").unwrap();
+        let value = data.parse_add_char_list("abcd").unwrap();
+        let addr = add_association_list(&mut data, &[(key, value)]);
+
+        let mut deserializer = GarnishDataDeserializer::new_borrowing_for_value(&data, addr);
+        let value = Named::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, Named { name: "abcd" });
+    }
+
+    #[test]
+    fn deserialize_borrowed_str_from_symbol_fails() {
+        let mut data = SimpleGarnishData::new();
+        let addr = data.parse_add_symbol("abcd").unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_borrowing_for_value(&data, addr);
+        let result = <&str>::deserialize(&mut deserializer);
+
+        assert_eq!(
+            result.unwrap_err().message(),
+            Some(
+                &"Deserialization of &str not supported, use owned type String instead."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn deserialize_str_without_borrowing_fails() {
+        let mut data = SimpleGarnishData::new();
+        let addr = data.parse_add_char_list("abcd").unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_read_only_for_value(&data, addr);
+        assert!(<&str>::deserialize(&mut deserializer).is_err());
+    }
+
+    fn add_byte_list(data: &mut SimpleGarnishData, bytes: &[u8]) -> usize {
+        data.start_byte_list().unwrap();
+        for b in bytes {
+            data.add_to_byte_list(*b).unwrap();
+        }
+        data.end_byte_list().unwrap()
+    }
+
+    #[test]
+    fn deserialize_byte_list_as_seq() {
+        let mut data = SimpleGarnishData::new();
+        let addr = add_byte_list(&mut data, &[1, 2, 255]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, addr);
+        let value = Vec::<u8>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, vec![1, 2, 255]);
+    }
+
+    #[test]
+    fn deserialize_byte_list_as_tuple() {
+        let mut data = SimpleGarnishData::new();
+        let addr = add_byte_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, addr);
+        let value = <[u8; 3]>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, [1, 2, 3]);
+    }
+
+    #[test]
+    fn deserialize_byte_list_as_shorter_tuple_fails() {
+        let mut data = SimpleGarnishData::new();
+        let addr = add_byte_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value(&mut data, addr);
+        assert!(<[u8; 2]>::deserialize(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_borrowed_bytes() {
+        let mut data = SimpleGarnishData::new();
+        let addr = add_byte_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_borrowing_for_value(&data, addr);
+        let value = <&[u8]>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(value, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn deserialize_borrowed_bytes_from_char_list_fails() {
+        let mut data = SimpleGarnishData::new();
+        let addr = data.parse_add_char_list("abc").unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_borrowing_for_value(&data, addr);
+        let result = <&[u8]>::deserialize(&mut deserializer);
+
+        assert_eq!(
+            result.unwrap_err().message(),
+            Some(
+                &"Deserialization of &[u8] not supported, use owned type Vec<u8> instead."
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn deserialize_bytes_without_borrowing_fails() {
+        let mut data = SimpleGarnishData::new();
+        let addr = add_byte_list(&mut data, &[1, 2, 3]);
+
+        let mut deserializer = GarnishDataDeserializer::new_read_only_for_value(&data, addr);
+        assert!(<&[u8]>::deserialize(&mut deserializer).is_err());
+    }
 }
@@ -1,10 +1,22 @@
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
-use garnish_lang_traits::GarnishData;
+use garnish_lang_traits::{GarnishData, GarnishDataType};
 
 use crate::traits::GarnishNumberConversions;
 
+/// Category of a [`GarnishSerializationError`] that can be matched on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GarnishSerializationErrorKind {
+    /// Error from the data object or one described only by its message.
+    Other,
+    /// Value was not one of the expected types.
+    TypeMismatch {
+        expected: Vec<GarnishDataType>,
+        found: GarnishDataType,
+    },
+}
+
 pub struct GarnishSerializationError<Data>
 where
     Data: GarnishData,
@@ -12,6 +24,8 @@ where
 {
     message: Option<String>,
     err: Option<Data::Error>,
+    path: Option<String>,
+    kind: GarnishSerializationErrorKind,
 }
 
 impl<Data> GarnishSerializationError<Data>
@@ -23,9 +37,37 @@ where
         Self {
             message: None,
             err: Some(err),
+            path: None,
+            kind: GarnishSerializationErrorKind::Other,
+        }
+    }
+
+    pub fn type_mismatch(expected: &[GarnishDataType], found: GarnishDataType) -> Self {
+        let expected_names = expected
+            .iter()
+            .map(|t| format!("{:?}", t))
+            .collect::<Vec<String>>();
+        let expected_str = match expected_names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            Some((last, _)) => last.clone(),
+            None => "nothing".to_string(),
+        };
+
+        Self {
+            message: Some(format!("Expected {}, found {:?}", expected_str, found)),
+            err: None,
+            path: None,
+            kind: GarnishSerializationErrorKind::TypeMismatch {
+                expected: expected.to_vec(),
+                found,
+            },
         }
     }
 
+    pub fn kind(&self) -> &GarnishSerializationErrorKind {
+        &self.kind
+    }
+
     pub fn message(&self) -> Option<&String> {
         self.message.as_ref()
     }
@@ -33,6 +75,49 @@ where
     pub fn error(&self) -> Option<&Data::Error> {
         self.err.as_ref()
     }
+
+    /// Location of the value that caused this error, if known. (e.g. "servers[2].port")
+    pub fn path(&self) -> Option<&String> {
+        self.path.as_ref()
+    }
+
+    /// Annotate this error, e.g. "while serializing request body".
+    /// Context is prepended to the message, keeping any backend error.
+    pub fn with_context(mut self, ctx: impl Into<String>) -> Self {
+        let ctx = ctx.into();
+        self.message = Some(match self.message.take() {
+            Some(message) => format!("{}: {}", ctx, message),
+            None => ctx,
+        });
+        self
+    }
+
+    pub(crate) fn with_path<F>(mut self, create_path: F) -> Self
+    where
+        F: FnOnce() -> String,
+    {
+        // first path set is the deepest and most accurate
+        if self.path.is_none() {
+            self.path = Some(create_path());
+        }
+        self
+    }
+}
+
+impl<Data> Clone for GarnishSerializationError<Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            message: self.message.clone(),
+            err: self.err.clone(),
+            path: self.path.clone(),
+            kind: self.kind.clone(),
+        }
+    }
 }
 
 impl<Data> From<&str> for GarnishSerializationError<Data>
@@ -44,6 +129,33 @@ where
         Self {
             message: Some(s.to_string()),
             err: None,
+            path: None,
+            kind: GarnishSerializationErrorKind::Other,
+        }
+    }
+}
+
+impl<Data> GarnishSerializationError<Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+{
+    fn write_parts<F>(&self, f: &mut Formatter<'_>, write_err: F) -> std::fmt::Result
+    where
+        F: FnOnce(&Data::Error, &mut Formatter<'_>) -> std::fmt::Result,
+    {
+        if let Some(path) = &self.path {
+            write!(f, "at {}: ", path)?;
+        }
+
+        match (&self.message, &self.err) {
+            (Some(message), Some(err)) => {
+                write!(f, "{}: ", message)?;
+                write_err(err, f)
+            }
+            (Some(message), None) => f.write_str(message),
+            (None, Some(err)) => write_err(err, f),
+            (None, None) => f.write_str("Unknown serialization error"),
         }
     }
 }
@@ -54,7 +166,7 @@ where
     Data::Number: GarnishNumberConversions,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(format!("{:?}", self.err).as_str())
+        self.write_parts(f, |err, f| write!(f, "{:?}", err))
     }
 }
 
@@ -64,7 +176,7 @@ where
     Data::Number: GarnishNumberConversions,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(format!("{:?}", self.err).as_str())
+        self.write_parts(f, |err, f| write!(f, "{}", err))
     }
 }
 
@@ -73,6 +185,10 @@ where
     Data: GarnishData,
     Data::Number: GarnishNumberConversions,
 {
+    // GarnishData requires its error to be 'static, so it can always be the source
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.err.as_ref().map(|err| err as &(dyn Error + 'static))
+    }
 }
 
 impl<Data> serde::ser::Error for GarnishSerializationError<Data>
@@ -87,6 +203,8 @@ where
         Self {
             message: Some(format!("{}", msg)),
             err: None,
+            path: None,
+            kind: GarnishSerializationErrorKind::Other,
         }
     }
 }
@@ -103,6 +221,8 @@ where
         Self {
             message: Some(format!("{}", msg)),
             err: None,
+            path: None,
+            kind: GarnishSerializationErrorKind::Other,
         }
     }
 }
@@ -114,3 +234,127 @@ where
 {
     Err(GarnishSerializationError::new(e))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use garnish_lang_simple_data::{DataError, SimpleGarnishData};
+
+    use garnish_lang_traits::GarnishDataType;
+
+    use crate::error::GarnishSerializationError;
+
+    #[test]
+    fn display_type_mismatch() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::type_mismatch(
+            &[
+                GarnishDataType::CharList,
+                GarnishDataType::Symbol,
+                GarnishDataType::Slice,
+            ],
+            GarnishDataType::Number,
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "Expected CharList, Symbol or Slice, found Number"
+        );
+    }
+
+    #[test]
+    fn display_message() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::from("Something went wrong");
+
+        assert_eq!(err.to_string(), "Something went wrong");
+        assert_eq!(format!("{:?}", err), "Something went wrong");
+    }
+
+    #[test]
+    fn display_data_error() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::new(DataError::from(
+            "Data failure".to_string(),
+        ));
+
+        assert_eq!(err.to_string(), "Data failure");
+    }
+
+    #[test]
+    fn clone_message() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::from("Something went wrong")
+            .with_context("while reading");
+        let cloned = err.clone();
+
+        assert_eq!(cloned.to_string(), err.to_string());
+        assert_eq!(cloned.kind(), err.kind());
+    }
+
+    #[test]
+    fn clone_data_error() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::new(DataError::from(
+            "Data failure".to_string(),
+        ));
+        let cloned = err.clone();
+
+        assert_eq!(cloned.error().unwrap().to_string(), "Data failure");
+        assert_eq!(cloned.to_string(), err.to_string());
+    }
+
+    #[test]
+    fn source_is_data_error() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::new(DataError::from(
+            "Data failure".to_string(),
+        ));
+
+        assert_eq!(err.source().unwrap().to_string(), "Data failure");
+    }
+
+    #[test]
+    fn source_without_data_error() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::from("Something went wrong");
+
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn display_message_and_path() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::from("Something went wrong")
+            .with_path(|| "servers[2].port".to_string());
+
+        assert_eq!(err.to_string(), "at servers[2].port: Something went wrong");
+    }
+
+    #[test]
+    fn display_with_context() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::from("Something went wrong")
+            .with_context("while serializing request body");
+
+        assert_eq!(
+            err.to_string(),
+            "while serializing request body: Something went wrong"
+        );
+    }
+
+    #[test]
+    fn display_with_nested_context() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::from("Something went wrong")
+            .with_context("while reading servers")
+            .with_context("while loading config");
+
+        assert_eq!(
+            err.to_string(),
+            "while loading config: while reading servers: Something went wrong"
+        );
+    }
+
+    #[test]
+    fn with_context_keeps_data_error() {
+        let err = GarnishSerializationError::<SimpleGarnishData>::new(DataError::from(
+            "backend failure".to_string(),
+        ))
+        .with_context("while serializing");
+
+        assert!(err.error().is_some());
+        assert!(err.to_string().starts_with("while serializing: "));
+    }
+}
@@ -0,0 +1,192 @@
+use garnish_lang_traits::GarnishData;
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    from_garnish_value, to_garnish_data, GarnishNumberConversions, GarnishSerializationError,
+    GarnishValueTree,
+};
+
+/// Add a JSON value to the given data, returning the address of the created value.
+///
+/// `null` becomes Unit, arrays become lists and objects become lists of associations.
+pub fn json_value_to_garnish<Data>(
+    data: &mut Data,
+    value: &Value,
+) -> Result<Data::Size, GarnishSerializationError<Data>>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+{
+    to_garnish_data(value, data)
+}
+
+/// Read the value at the given address of the given data as a JSON value.
+///
+/// Unit becomes `null`, symbols and chars become strings, byte lists become arrays of numbers
+/// and lists made up of only associations become objects.
+/// Object keys that aren't strings use their display form and when an association list
+/// has duplicate keys, the last one is kept.
+pub fn garnish_to_json_value<Data>(
+    data: &mut Data,
+    addr: Data::Size,
+) -> Result<Value, GarnishSerializationError<Data>>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    let tree: GarnishValueTree = from_garnish_value(data, addr)?;
+    tree_to_json(tree).map_err(|e| GarnishSerializationError::from(e.as_str()))
+}
+
+fn tree_to_json(tree: GarnishValueTree) -> Result<Value, String> {
+    Ok(match tree {
+        GarnishValueTree::Unit => Value::Null,
+        GarnishValueTree::Bool(b) => Value::Bool(b),
+        GarnishValueTree::Integer(i) => Value::from(i),
+        // non-finite floats have no JSON form
+        GarnishValueTree::Float(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+        GarnishValueTree::Char(c) => Value::String(c.to_string()),
+        GarnishValueTree::Str(s) => Value::String(s),
+        GarnishValueTree::Bytes(bytes) => Value::from(bytes),
+        GarnishValueTree::List(items) => Value::Array(
+            items
+                .into_iter()
+                .map(tree_to_json)
+                .collect::<Result<Vec<Value>, String>>()?,
+        ),
+        GarnishValueTree::Map(entries) => {
+            let mut map = Map::new();
+            for (key, value) in entries {
+                let key = match key {
+                    GarnishValueTree::Str(s) => s,
+                    GarnishValueTree::Char(c) => c.to_string(),
+                    GarnishValueTree::Integer(i) => i.to_string(),
+                    GarnishValueTree::Float(f) => f.to_string(),
+                    GarnishValueTree::Bool(b) => b.to_string(),
+                    k => Err(format!("Cannot use {:?} as a JSON object key.", k))?,
+                };
+                map.insert(key, tree_to_json(value)?);
+            }
+            Value::Object(map)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use garnish_lang_simple_data::{SimpleGarnishData, SimpleNumber};
+    use garnish_lang_traits::{GarnishData, GarnishDataType};
+    use serde_json::{json, Value};
+
+    use crate::from_garnish_value;
+    use crate::json::{garnish_to_json_value, json_value_to_garnish};
+    use crate::test_data::UncachedGarnishData;
+
+    #[test]
+    fn null_is_unit() {
+        let mut data = SimpleGarnishData::new();
+        let addr = json_value_to_garnish(&mut data, &json!(null)).unwrap();
+
+        assert_eq!(data.get_data_type(addr).unwrap(), GarnishDataType::Unit);
+        assert_eq!(garnish_to_json_value(&mut data, addr).unwrap(), json!(null));
+    }
+
+    #[test]
+    fn round_trip_object() {
+        let value = json!({
+            "name": "garnish",
+            "count": 3,
+            "ratio": 0.5,
+            "enabled": true,
+            "tags": ["a", "b"],
+            "nested": { "missing": null },
+        });
+
+        let mut data = UncachedGarnishData::new();
+        let addr = json_value_to_garnish(&mut data, &value).unwrap();
+
+        // object keys are added with add_symbol_from, which doesn't record the symbol's name
+        for key in [
+            "name", "count", "ratio", "enabled", "tags", "nested", "missing",
+        ] {
+            data.parse_add_symbol(key).unwrap();
+        }
+
+        assert_eq!(garnish_to_json_value(&mut data, addr).unwrap(), value);
+    }
+
+    #[test]
+    fn deserialize_heterogeneous_list_into_values() {
+        let mut data = SimpleGarnishData::new();
+        let nested_one = data.add_number(SimpleNumber::Integer(1)).unwrap();
+        let nested_two = data.add_number(SimpleNumber::Integer(2)).unwrap();
+        data.start_list(2).unwrap();
+        data.add_to_list(nested_one, false).unwrap();
+        data.add_to_list(nested_two, false).unwrap();
+        let nested = data.end_list().unwrap();
+
+        let items = [
+            data.add_number(SimpleNumber::Integer(10)).unwrap(),
+            data.add_number(SimpleNumber::Float(2.5)).unwrap(),
+            data.parse_add_char_list("\"text\"").unwrap(),
+            data.add_true().unwrap(),
+            data.add_unit().unwrap(),
+            nested,
+        ];
+        data.start_list(items.len()).unwrap();
+        for item in items {
+            data.add_to_list(item, false).unwrap();
+        }
+        let addr = data.end_list().unwrap();
+
+        let values: Vec<Value> = from_garnish_value(&mut data, addr).unwrap();
+
+        assert!(values[0].is_i64());
+        assert!(values[1].is_f64());
+        assert!(values[2].is_string());
+        assert!(values[3].is_boolean());
+        assert!(values[4].is_null());
+        assert!(values[5].is_array());
+        assert_eq!(
+            values,
+            vec![
+                json!(10),
+                json!(2.5),
+                json!("text"),
+                json!(true),
+                json!(null),
+                json!([1, 2])
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_keys_keep_last() {
+        let mut data = SimpleGarnishData::new();
+        let key = data.parse_add_symbol("key").unwrap();
+        let one = data.add_number(SimpleNumber::Integer(1)).unwrap();
+        let two = data.add_number(SimpleNumber::Integer(2)).unwrap();
+        let first = data.add_pair((key, one)).unwrap();
+        let second = data.add_pair((key, two)).unwrap();
+
+        data.start_list(2).unwrap();
+        data.add_to_list(first, true).unwrap();
+        data.add_to_list(second, true).unwrap();
+        let addr = data.end_list().unwrap();
+
+        assert_eq!(
+            garnish_to_json_value(&mut data, addr).unwrap(),
+            json!({ "key": 2 })
+        );
+    }
+}
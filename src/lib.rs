@@ -2,6 +2,8 @@
 /// Mapping from Serde data model to Garnish value
 /// | *Serde Type*                          | *Example*                         | *Garnish Value*               | *Garnish Type*                |
 /// | i8, i16, i32, i64, u8, u16, u32, u64  | 100                               | 100                           | Number                        |
+/// | i128, u128 (64 bit range)             | 100                               | 100                           | Number                        |
+/// | i128, u128 (beyond 64 bits)           | u64::MAX as u128 + 1              | 18446744073709551616          | Number                        |
 /// | f32, f64                              | 5.0                               | 5.0                           | Number                        |
 /// | bool                                  | true                              | $?                            | True, False                   |
 /// | char                                  | 'a'                               | "a"                           | Char                          |
@@ -21,6 +23,31 @@
 /// | Map                                   | HashMap<K, V>                     | ;one = 1, ;two = 2            | List - with associations      |
 /// | Struct                                | struct S { one: u8, two: u8 }     | ;one = 1, ;two = 2            | List - with associations      |
 /// | Struct Variant                        | enum E { S { one: u8, two: u8 }   | ;E::S, (;one = 1, ;two = 2)   | List (enum name, struct list) |
+/// | Range, RangeInclusive (GarnishRange)  | 0..10, 0..=10                     | 0..9, 0..10                   | Range                         |
+/// | GarnishPair                           | GarnishPair("a", 1)               | ;a = 1                        | Pair                          |
+/// | GarnishAssocList                      | GarnishAssocList(vec![("a", 1)])  | ;a = 1,                       | List - with associations      |
+///
+/// Ranges are only converted with [`RangeBehavior::GarnishRange`]. They are detected by the struct names
+/// `Range` and `RangeInclusive` with the two fields `start` and `end`.
+/// Garnish ranges are inclusive, so the end of an exclusive `Range` must be an integer.
+///
+/// Integers are converted back after being created and fail to serialize when they don't match,
+/// so values too large for the data's number type aren't silently truncated.
+///
+/// Integers beyond the 64 bit range require numbers implementing [`GarnishNumber128Conversions`].
+/// This is opt in, enabled with `with_128_bit_numbers` on [`GarnishDataSerializer`] and
+/// [`GarnishDataDeserializer`], so [`GarnishNumberConversions`] only needs 64 bit conversions.
+/// Without it these values fail to serialize.
+///
+/// `std::ops::Bound` uses the enum conversions, `Unbounded` as a Symbol and
+/// `Included` and `Excluded` as a List of the variant name and value.
+///
+/// Map keys are converted to symbols, unless using [`MapKeyBehavior::PreserveType`]
+/// which keeps the serialized key, such as a Number.
+/// Number keys are read back as integer map keys, so keyed collections like
+/// `HashMap<i64, Vec<String>>` round trip with [`MapKeyBehavior::PreserveType`].
+/// [`MapKeyBehavior::PreserveNumbers`] keeps only number keys, so a `#[serde(flatten)]`
+/// number keyed map is stored alongside the symbol keys of its struct's fields.
 ///
 /// ## Deserialization Type Conversions
 ///
@@ -28,16 +55,16 @@
 /// | ()                | Unit              | Unit, None                                                            |
 /// | $?                | True              | bool                                                                  |
 /// | $!                | False             | bool                                                                  |
-/// | 5                 | Number            | i8, i16, i32, i64, u8, u16, u32, u64                                  |
+/// | 5                 | Number            | i8, i16, i32, i64, u8, u16, u32, u64, char, Enum/Variant by index     |
 /// | 5.0               | Number            | f32, f64                                                              |
 /// | "a"               | Char              | char                                                                  |
-/// | "abcd"            | CharList          | String                                                                |
+/// | "abcd"            | CharList          | String, char (single character), &str (with GarnishBorrowedData)      |
 /// | '1'               | Byte              | u8                                                                    |
-/// | '1234'            | ByteList          | Vec<u8>                                                               |
-/// | #5                | Type              | Enum                                                                  |
+/// | '1234'            | ByteList          | Vec<u8>, String (with byte_list_as_string), &[u8] (borrowed)          |
+/// | #5                | Type              | Enum, u8, u32 (type id)                                               |
 /// | ;symbol           | Symbol            | Enum/Unit Variant                                                     |
-/// | 5 = 10            | Pair              | ?                                                                     |
-/// | 5..10             | Range             | ?                                                                     |
+/// | 5 = 10            | Pair              | Two element tuple, Newtype Variant (;Variant = value)                 |
+/// | 5..10             | Range             | Range, RangeInclusive, Struct with start and end fields               |
 /// | 5 <> 10           | Concatenation     | Vec<T>, String, Map, Struct, Tuple                                    |
 /// | list ~ 1..3       | Slice             | Vec<T>, String, Map, Struct, Tuple                                    |
 /// | 10, 20, 30        | List              | Vec<T>, Map, Struct, Tuple, Newtype/Tuple/Struct Variant              |
@@ -45,17 +72,1229 @@
 /// | external_value    | External          | ?                                                                     |
 /// | custom_type       | Custom            | ?                                                                     |
 ///
+/// Variant names serialized with [`VariantNameBehavior::ShortString`] or [`VariantNameBehavior::FullString`]
+/// are read from a CharList the same way as from a Symbol.
+///
+/// ## Features
+/// * `json` - conversions between `serde_json::Value` and Garnish values.
+/// * `simple_data` - [`GarnishStringConversions`] and [`GarnishBorrowedData`] for `SimpleGarnishData`,
+///   so it can be used with [`GarnishDataDeserializer::new_read_only`], [`GarnishDataDeserializer::new_borrowing`],
+///   [`GarnishValueRef`] and as the source of [`transcode`]. [`GarnishValueMut`] works without them.
+///
+mod assoc_list;
+mod capture;
 mod deserializer;
 mod error;
+#[cfg(feature = "json")]
+mod json;
+pub mod optional;
 mod options;
+mod pair;
 mod serializer;
+#[cfg(any(test, feature = "simple_data"))]
+mod simple_data;
 mod traits;
+mod transcode;
+mod value_tree;
 
-pub use deserializer::GarnishDataDeserializer;
-pub use error::GarnishSerializationError;
+#[cfg(test)]
+mod test_data;
+
+pub use assoc_list::GarnishAssocList;
+pub use deserializer::{
+    GarnishDataDeserializer, GarnishValueMut, GarnishValueRef, GarnishValuesIter,
+};
+pub use error::{GarnishSerializationError, GarnishSerializationErrorKind};
+#[cfg(feature = "json")]
+pub use json::{garnish_to_json_value, json_value_to_garnish};
 pub use options::*;
+pub use pair::GarnishPair;
 pub use serializer::*;
 pub use traits::*;
+pub use value_tree::GarnishValueTree;
+
+use garnish_lang_traits::GarnishData;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use transcode::Transcoder;
+
+/// Serialize a value into the given data, returning the address of the created value.
+pub fn to_garnish_data<T, Data>(
+    value: &T,
+    data: &mut Data,
+) -> Result<Data::Size, GarnishSerializationError<Data>>
+where
+    T: Serialize + ?Sized,
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+{
+    value.serialize(&mut GarnishDataSerializer::new(data))
+}
+
+/// Deserialize the current value of the given data.
+pub fn from_garnish_data<T, Data>(data: &mut Data) -> Result<T, GarnishSerializationError<Data>>
+where
+    T: DeserializeOwned,
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    T::deserialize(&mut GarnishDataDeserializer::new(data))
+}
+
+/// Deserialize the value at the given address of the given data.
+pub fn from_garnish_value<T, Data>(
+    data: &mut Data,
+    addr: Data::Size,
+) -> Result<T, GarnishSerializationError<Data>>
+where
+    T: DeserializeOwned,
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    T::deserialize(&mut GarnishDataDeserializer::new_for_value(data, addr))
+}
+
+/// Materialize the value at the given address into a [`GarnishValueTree`].
+pub fn to_value_tree<Data>(
+    data: &mut Data,
+    addr: Data::Size,
+) -> Result<GarnishValueTree, GarnishSerializationError<Data>>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+{
+    from_garnish_value(data, addr)
+}
+
+/// Copy the value at the given address of one data object into another,
+/// possibly of a different backend, without going through a Rust type.
+///
+/// Values are read as with [`GarnishValueTree`], so symbols are written as char lists
+/// and lists of only associations are written as maps.
+///
+/// Source data is only read, so it must implement [`GarnishStringConversions`].
+/// `SimpleGarnishData` implements it with the `simple_data` feature.
+pub fn transcode<Data, Data2>(
+    source: &Data,
+    addr: Data::Size,
+    destination: &mut Data2,
+) -> Result<Data2::Size, GarnishSerializationError<Data2>>
+where
+    Data: GarnishStringConversions,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Size: Into<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+    Data::Byte: Into<u8>,
+    Data2: GarnishData,
+    Data2::Number: GarnishNumberConversions,
+    Data2::Size: From<usize>,
+    Data2::Char: From<char>,
+    Data2::Char: Into<char>,
+    Data2::Byte: From<u8>,
+{
+    let mut deserializer = GarnishDataDeserializer::new_read_only_for_value(source, addr);
+    to_garnish_data(&Transcoder::new(&mut deserializer), destination)
+}
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use garnish_lang_simple_data::{SimpleData, SimpleGarnishData, SimpleNumber};
+    use garnish_lang_traits::{GarnishData, GarnishDataType};
+    use serde::{Deserialize, Serialize};
+
+    use crate::test_data::{UncachedGarnishData, WideGarnishData};
+    use crate::{
+        from_garnish_data, from_garnish_value, to_garnish_data, transcode,
+        CharSerializationBehavior, EmptyStructBehavior, GarnishAssocList, GarnishDataDeserializer,
+        GarnishDataSerializer, GarnishDeserializationOptions, GarnishPair,
+        GarnishSerializationOptions, MapKeyBehavior, OptionalBehavior, RangeBehavior,
+        SequenceBehavior, StructBehavior,
+    };
+    use std::collections::HashMap;
+    use std::ops::{Bound, Range, RangeInclusive};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SomeStruct {
+        one: i32,
+        two: String,
+        three: bool,
+    }
+
+    fn some_struct() -> SomeStruct {
+        SomeStruct {
+            one: 100,
+            two: "abcd".to_string(),
+            three: true,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SomeBytes {
+        #[serde(with = "serde_bytes")]
+        bytes: Vec<u8>,
+    }
+
+    #[test]
+    fn to_data() {
+        let mut data = SimpleGarnishData::new();
+
+        let addr = to_garnish_data(&100, &mut data).unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+    }
+
+    #[test]
+    fn round_trip_from_value() {
+        let mut data = SimpleGarnishData::new();
+
+        let addr = to_garnish_data(&some_struct(), &mut data).unwrap();
+        let value: SomeStruct = from_garnish_value(&mut data, addr).unwrap();
+
+        assert_eq!(value, some_struct());
+    }
+
+    #[test]
+    fn round_trip_from_current_value() {
+        let mut data = SimpleGarnishData::new();
+
+        let addr = to_garnish_data(&some_struct(), &mut data).unwrap();
+        data.push_value_stack(addr).unwrap();
+
+        let value: SomeStruct = from_garnish_data(&mut data).unwrap();
+
+        assert_eq!(value, some_struct());
+    }
+
+    #[test]
+    fn round_trip_byte_field() {
+        let mut data = SimpleGarnishData::new();
+        let value = SomeBytes {
+            bytes: vec![10, 20, 30],
+        };
+
+        let addr = to_garnish_data(&value, &mut data).unwrap();
+
+        let (list, _) = data.get_data().get(addr).unwrap().as_list().unwrap();
+        let (_, bytes) = data.get_data().get(list[0]).unwrap().as_pair().unwrap();
+        assert_eq!(
+            data.get_data().get(bytes).unwrap(),
+            &SimpleData::ByteList(vec![10, 20, 30])
+        );
+
+        let result: SomeBytes = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn serialize_utf8_path_as_char_list() {
+        let mut data = SimpleGarnishData::new();
+
+        let addr = to_garnish_data(std::path::Path::new("dir/file.txt"), &mut data).unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::CharList("dir/file.txt".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn serialize_non_utf8_path_fails() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut data = SimpleGarnishData::new();
+        let path = std::path::Path::new(std::ffi::OsStr::from_bytes(&[b'a', 0xff]));
+
+        let err = to_garnish_data(path, &mut data).unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            Some(&"path contains invalid UTF-8 characters".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn round_trip_non_utf8_os_string() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let mut data = SimpleGarnishData::new();
+        let value = std::ffi::OsString::from_vec(vec![b'a', 0xff]);
+
+        // serde stores OsString as the variant OsString::Unix holding its bytes, which keeps them exactly
+        let addr = to_garnish_data(&value, &mut data).unwrap();
+        let result: std::ffi::OsString = from_garnish_value(&mut data, addr).unwrap();
+
+        assert_eq!(result, value);
+    }
+
+    fn assert_char_round_trip(behavior: CharSerializationBehavior, c: char) {
+        // SimpleGarnishData reports char list lengths in utf-8 bytes
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().char_serialization_behavior(behavior),
+        );
+
+        let addr = c.serialize(&mut serializer).unwrap();
+        let result: char = from_garnish_value(&mut data, addr).unwrap();
+
+        assert_eq!(result, c);
+    }
+
+    #[test]
+    fn round_trip_char_in_each_mode() {
+        for behavior in [
+            CharSerializationBehavior::Char,
+            CharSerializationBehavior::CharList,
+            CharSerializationBehavior::CodePoint,
+        ] {
+            assert_char_round_trip(behavior, 'a');
+            assert_char_round_trip(behavior, '🦀');
+        }
+    }
+
+    fn assert_char_map_round_trip(behavior: CharSerializationBehavior) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().char_serialization_behavior(behavior),
+        );
+
+        let map = HashMap::from([('a', 'b'), ('c', 'd')]);
+        let addr = map.serialize(&mut serializer).unwrap();
+
+        let result: HashMap<char, char> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, map, "{:?}", behavior);
+    }
+
+    #[test]
+    fn round_trip_char_map_in_each_mode() {
+        for behavior in [
+            CharSerializationBehavior::Char,
+            CharSerializationBehavior::CharList,
+            CharSerializationBehavior::CodePoint,
+        ] {
+            assert_char_map_round_trip(behavior);
+        }
+    }
+
+    fn skip_unit_fields_data<T: Serialize>(value: &T) -> (SimpleGarnishData, usize) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().skip_unit_fields(true),
+        );
+
+        let addr = value.serialize(&mut serializer).unwrap();
+        (data, addr)
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Marked {
+        value: i32,
+        marker: (),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct DefaultMarked {
+        value: i32,
+        #[serde(default)]
+        marker: (),
+    }
+
+    #[test]
+    fn skipped_unit_field_is_missing_when_deserialized() {
+        let (mut data, addr) = skip_unit_fields_data(&Marked {
+            value: 10,
+            marker: (),
+        });
+
+        let err = from_garnish_value::<Marked, _>(&mut data, addr).unwrap_err();
+        assert_eq!(err.to_string(), "missing field `marker`");
+    }
+
+    #[test]
+    fn skipped_unit_field_with_default_round_trips() {
+        let value = DefaultMarked {
+            value: 10,
+            marker: (),
+        };
+        let (mut data, addr) = skip_unit_fields_data(&value);
+
+        let result: DefaultMarked = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    fn range_data<T: Serialize>(value: &T) -> (SimpleGarnishData, usize) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().range_behavior(RangeBehavior::GarnishRange),
+        );
+
+        let addr = value.serialize(&mut serializer).unwrap();
+        (data, addr)
+    }
+
+    #[test]
+    fn round_trip_range_inclusive() {
+        let (mut data, addr) = range_data(&(2..=8));
+        assert_eq!(
+            data.get_data().get(addr).unwrap().get_data_type(),
+            GarnishDataType::Range
+        );
+
+        let value: RangeInclusive<i32> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(value, 2..=8);
+    }
+
+    #[test]
+    fn round_trip_range() {
+        let (mut data, addr) = range_data(&(2..8));
+
+        let value: Range<u32> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(value, 2..8);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Span {
+        start: usize,
+        end: usize,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct ReversedSpan {
+        end: usize,
+        start: usize,
+    }
+
+    #[test]
+    fn range_into_custom_struct() {
+        let (mut data, addr) = range_data(&(2..=8));
+
+        let value: Span = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(value, Span { start: 2, end: 8 });
+
+        let value: ReversedSpan = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(value, ReversedSpan { end: 8, start: 2 });
+    }
+
+    #[test]
+    fn round_trip_custom_span() {
+        let mut data = SimpleGarnishData::new();
+        let addr = to_garnish_data(&Span { start: 2, end: 8 }, &mut data).unwrap();
+
+        let value: Span = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(value, Span { start: 2, end: 8 });
+    }
+
+    #[test]
+    fn range_into_struct_with_other_fields() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Labeled {
+            start: usize,
+            end: usize,
+            label: String,
+        }
+
+        let (mut data, addr) = range_data(&(2..=8));
+
+        let err = from_garnish_value::<Labeled, _>(&mut data, addr).unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some(&"Range can only be deserialized into a struct with exactly the fields start and end, found [\"start\", \"end\", \"label\"].".to_string())
+        );
+    }
+
+    fn assert_bound_round_trip(value: Bound<i32>) {
+        let mut data = SimpleGarnishData::new();
+        let addr = to_garnish_data(&value, &mut data).unwrap();
+
+        let result: Bound<i32> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn round_trip_bound_included() {
+        assert_bound_round_trip(Bound::Included(5));
+    }
+
+    #[test]
+    fn round_trip_bound_excluded() {
+        assert_bound_round_trip(Bound::Excluded(5));
+    }
+
+    #[test]
+    fn round_trip_bound_unbounded() {
+        assert_bound_round_trip(Bound::Unbounded);
+    }
+
+    #[test]
+    fn round_trip_map_with_number_keys() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().map_key_behavior(MapKeyBehavior::PreserveType),
+        );
+
+        let mut map = HashMap::new();
+        map.insert(1u32, String::from("one"));
+        map.insert(20u32, String::from("twenty"));
+        let addr = map.serialize(&mut serializer).unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        for item in list.iter() {
+            let (left, _) = data.get_data().get(*item).unwrap().as_pair().unwrap();
+            assert_eq!(
+                data.get_data().get(left).unwrap().get_data_type(),
+                GarnishDataType::Number
+            );
+        }
+
+        let result: HashMap<u32, String> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, map);
+    }
+
+    #[test]
+    fn round_trip_map_of_number_keys_to_lists() {
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().map_key_behavior(MapKeyBehavior::PreserveType),
+        );
+
+        let map: HashMap<i64, Vec<String>> = HashMap::from([
+            (1, vec!["a".to_string(), "b".to_string()]),
+            (-20, vec![]),
+            (300, vec!["c".to_string()]),
+        ]);
+        let addr = map.serialize(&mut serializer).unwrap();
+
+        let result: HashMap<i64, Vec<String>> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, map);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct FlattenedNumberKeys {
+        name: String,
+        #[serde(flatten)]
+        extra: HashMap<i64, String>,
+    }
+
+    #[test]
+    fn round_trip_flattened_map_with_number_keys() {
+        let value = FlattenedNumberKeys {
+            name: "config".to_string(),
+            extra: HashMap::from([(1, "one".to_string()), (-20, "minus twenty".to_string())]),
+        };
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().map_key_behavior(MapKeyBehavior::PreserveNumbers),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+        data.parse_add_symbol("name").unwrap();
+
+        // field and entries are all associations of a single list
+        assert_eq!(data.get_list_associations_len(addr).unwrap(), 3);
+        let mut key_types = vec![];
+        for i in 0..3 {
+            let item = data.get_list_item(addr, SimpleNumber::Integer(i)).unwrap();
+            let (key, _) = data.get_pair(item).unwrap();
+            key_types.push(data.get_data_type(key).unwrap());
+        }
+        key_types.sort();
+        assert_eq!(
+            key_types,
+            vec![
+                GarnishDataType::Number,
+                GarnishDataType::Number,
+                GarnishDataType::Symbol
+            ]
+        );
+
+        let result: FlattenedNumberKeys = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        count: i32,
+        label: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct FlattenedStruct {
+        name: String,
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    #[test]
+    fn round_trip_flattened_struct() {
+        let value = FlattenedStruct {
+            name: "config".to_string(),
+            inner: Inner {
+                count: 3,
+                label: "three".to_string(),
+            },
+        };
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let addr = value.serialize(&mut serializer).unwrap();
+        for key in ["name", "count", "label"] {
+            data.parse_add_symbol(key).unwrap();
+        }
+
+        // inner fields are associations of the outer list instead of a nested list
+        assert_eq!(data.get_list_len(addr).unwrap(), 3);
+        assert_eq!(data.get_list_associations_len(addr).unwrap(), 3);
+        for i in 0..3 {
+            let item = data.get_list_item(addr, SimpleNumber::Integer(i)).unwrap();
+            let (key, value) = data.get_pair(item).unwrap();
+            assert_eq!(data.get_data_type(key).unwrap(), GarnishDataType::Symbol);
+            assert_ne!(data.get_data_type(value).unwrap(), GarnishDataType::List);
+        }
+
+        let result: FlattenedStruct = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    fn assert_unit_symbol_option_round_trip(value: Option<i32>) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+        );
+        let result = Option::<i32>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn round_trip_option_none_as_unit_symbol() {
+        assert_unit_symbol_option_round_trip(None);
+    }
+
+    #[test]
+    fn round_trip_option_some_as_unit_symbol() {
+        assert_unit_symbol_option_round_trip(Some(10));
+    }
+
+    #[test]
+    fn round_trip_map_of_options_as_unit_symbol() {
+        let map: HashMap<String, Option<i32>> = HashMap::from([
+            ("timeout".to_string(), Some(30)),
+            ("retries".to_string(), None),
+            ("delay".to_string(), Some(0)),
+        ]);
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+        );
+        let addr = map.serialize(&mut serializer).unwrap();
+        for key in ["timeout", "retries", "delay"] {
+            data.parse_add_symbol(key).unwrap();
+        }
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+        );
+        let result = HashMap::<String, Option<i32>>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(result, map);
+    }
+
+    #[test]
+    fn round_trip_json_compat_numbers() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Numbers {
+            count: u32,
+            offset: i64,
+            ratio: f64,
+        }
+
+        let value = Numbers {
+            count: 10,
+            offset: -20,
+            ratio: 0.5,
+        };
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().json_compat(true),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        for item in list.iter() {
+            let (_, right) = data.get_data().get(*item).unwrap().as_pair().unwrap();
+            assert!(matches!(
+                data.get_data().get(right).unwrap(),
+                SimpleData::Number(SimpleNumber::Float(_))
+            ));
+        }
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().json_compat(true),
+        );
+        let result = Numbers::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(result, value);
+    }
+
+    fn assert_nested_option_round_trip(value: Option<Option<i32>>) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().preserve_option_nesting(true),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let mut deserializer = GarnishDataDeserializer::new_for_value_with_options(
+            &mut data,
+            addr,
+            GarnishDeserializationOptions::new().preserve_option_nesting(true),
+        );
+        let result = Option::<Option<i32>>::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn round_trip_nested_option_none() {
+        assert_nested_option_round_trip(None);
+    }
+
+    #[test]
+    fn round_trip_nested_option_some_none() {
+        assert_nested_option_round_trip(Some(None));
+    }
+
+    #[test]
+    fn round_trip_nested_option_some_some() {
+        assert_nested_option_round_trip(Some(Some(5)));
+    }
+
+    #[test]
+    fn nested_option_some_none_is_lost_without_option() {
+        let mut data = SimpleGarnishData::new();
+        let addr = to_garnish_data(&Some(None::<i32>), &mut data).unwrap();
+
+        let result: Option<Option<i32>> = from_garnish_value(&mut data, addr).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn from_garnish_pair() {
+        let mut data = SimpleGarnishData::new();
+        let left = data.parse_add_symbol("a").unwrap();
+        let right = data.add_number(SimpleNumber::Integer(1)).unwrap();
+        let addr = data.add_pair((left, right)).unwrap();
+
+        let result: GarnishPair<String, i32> = from_garnish_value(&mut data, addr).unwrap();
+
+        assert_eq!(result, GarnishPair("a".to_string(), 1));
+    }
+
+    fn assoc_list() -> GarnishAssocList<i32> {
+        GarnishAssocList(vec![
+            ("zeta".to_string(), 1),
+            ("alpha".to_string(), 2),
+            ("mid".to_string(), 3),
+        ])
+    }
+
+    fn assoc_list_data() -> (SimpleGarnishData, usize) {
+        let mut data = SimpleGarnishData::new();
+        let addr = to_garnish_data(&assoc_list(), &mut data).unwrap();
+
+        // map keys are added with add_symbol_from, which doesn't record the symbol's name
+        for (key, _) in assoc_list().0 {
+            data.parse_add_symbol(&key).unwrap();
+        }
+
+        (data, addr)
+    }
+
+    #[test]
+    fn round_trip_assoc_list_keeps_order() {
+        let (mut data, addr) = assoc_list_data();
+
+        let result: GarnishAssocList<i32> = from_garnish_value(&mut data, addr).unwrap();
+
+        assert_eq!(result, assoc_list());
+    }
+
+    #[test]
+    fn assoc_list_serializes_associations() {
+        let (mut data, addr) = assoc_list_data();
+
+        assert_eq!(data.get_list_associations_len(addr).unwrap(), 3);
+
+        let sym = <SimpleGarnishData as GarnishData>::parse_symbol("alpha").unwrap();
+        let value = data.get_list_item_with_symbol(addr, sym).unwrap().unwrap();
+        assert_eq!(data.get_number(value).unwrap(), SimpleNumber::Integer(2));
+
+        let map: HashMap<String, i32> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(
+            map,
+            HashMap::from([
+                ("zeta".to_string(), 1),
+                ("alpha".to_string(), 2),
+                ("mid".to_string(), 3)
+            ])
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NoFields {}
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SkippedFields {
+        #[serde(skip)]
+        one: i32,
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        two: Option<i32>,
+    }
+
+    fn assert_empty_struct_round_trip<T>(
+        value: T,
+        behavior: EmptyStructBehavior,
+        expected: GarnishDataType,
+    ) where
+        T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+    {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().empty_struct_behavior(behavior),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        assert_eq!(
+            data.get_data_type(addr).unwrap(),
+            expected,
+            "{:?}",
+            behavior
+        );
+
+        let result: T = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value, "{:?}", behavior);
+    }
+
+    #[test]
+    fn round_trip_fieldless_struct() {
+        assert_empty_struct_round_trip(
+            NoFields {},
+            EmptyStructBehavior::EmptyList,
+            GarnishDataType::List,
+        );
+        assert_empty_struct_round_trip(
+            NoFields {},
+            EmptyStructBehavior::Unit,
+            GarnishDataType::Unit,
+        );
+    }
+
+    #[test]
+    fn round_trip_struct_with_skipped_fields() {
+        let value = || SkippedFields { one: 0, two: None };
+
+        assert_empty_struct_round_trip(
+            value(),
+            EmptyStructBehavior::EmptyList,
+            GarnishDataType::List,
+        );
+        assert_empty_struct_round_trip(value(), EmptyStructBehavior::Unit, GarnishDataType::Unit);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ids(Vec<u64>);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Scores(HashMap<String, i32>);
+
+    // newtype structs are transparent, the typing behavior shouldn't add nesting around the collection
+    fn assert_newtype_collection_round_trip<T>(value: T, len: usize, keys: &[&str])
+    where
+        T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+    {
+        for behavior in [StructBehavior::ExcludeTyping, StructBehavior::IncludeTyping] {
+            let mut data = UncachedGarnishData::new();
+            let mut serializer = GarnishDataSerializer::new_with_options(
+                &mut data,
+                GarnishSerializationOptions::new().struct_typing_behavior(behavior),
+            );
+            let addr = value.serialize(&mut serializer).unwrap();
+            // map keys are added with add_symbol_from, which doesn't record the symbol's name
+            for key in keys {
+                data.parse_add_symbol(key).unwrap();
+            }
+
+            assert_eq!(data.get_list_len(addr).unwrap(), len, "{:?}", behavior);
+
+            let result: T = from_garnish_value(&mut data, addr).unwrap();
+            assert_eq!(result, value, "{:?}", behavior);
+        }
+    }
+
+    #[test]
+    fn round_trip_newtype_vec() {
+        assert_newtype_collection_round_trip(Ids(vec![10, 20, 30]), 3, &[]);
+    }
+
+    #[test]
+    fn round_trip_newtype_map() {
+        let scores = Scores(HashMap::from([
+            ("one".to_string(), 1),
+            ("two".to_string(), 2),
+        ]));
+
+        assert_newtype_collection_round_trip(scores, 2, &["one", "two"]);
+    }
+
+    #[test]
+    fn round_trip_vec_of_one_tuples() {
+        let value = vec![(10,), (20,), (30,)];
+
+        let mut data = UncachedGarnishData::new();
+        let addr = to_garnish_data(&value, &mut data).unwrap();
+
+        assert_eq!(data.get_list_len(addr).unwrap(), 3);
+        let first = data.get_list_item(addr, SimpleNumber::Integer(0)).unwrap();
+        assert_eq!(data.get_data_type(first).unwrap(), GarnishDataType::List);
+
+        let result: Vec<(i32,)> = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn round_trip_sequences_as_concatenations() {
+        let value = (vec![vec![1, 2], vec![], vec![3]], ("a".to_string(), true));
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().sequence_behavior(SequenceBehavior::Concatenation),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        assert_eq!(
+            data.get_data_type(addr).unwrap(),
+            GarnishDataType::Concatenation
+        );
+
+        let result: (Vec<Vec<i32>>, (String, bool)) = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NestedInner {
+        a: i32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum NestingEnum {
+        T(NestedInner, i32),
+        S { inner: NestedInner, b: i32 },
+    }
+
+    fn assert_nesting_enum_round_trip(value: NestingEnum) {
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let result: NestingEnum = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn round_trip_tuple_variant_containing_struct() {
+        assert_nesting_enum_round_trip(NestingEnum::T(NestedInner { a: 1 }, 2));
+    }
+
+    #[test]
+    fn round_trip_struct_variant_containing_struct() {
+        assert_nesting_enum_round_trip(NestingEnum::S {
+            inner: NestedInner { a: 1 },
+            b: 2,
+        });
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct WideIntegers {
+        signed: i128,
+        unsigned: u128,
+    }
+
+    #[test]
+    fn round_trip_128_bit_integers_with_128_bit_numbers() {
+        let value = WideIntegers {
+            signed: i128::MAX,
+            unsigned: i128::MAX as u128,
+        };
+
+        let mut data = WideGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data).with_128_bit_numbers();
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let mut deserializer =
+            GarnishDataDeserializer::new_for_value(&mut data, addr).with_128_bit_numbers();
+        let result = WideIntegers::deserialize(&mut deserializer).unwrap();
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn round_trip_i128_min_with_128_bit_numbers() {
+        let mut data = WideGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data).with_128_bit_numbers();
+        let addr = i128::MIN.serialize(&mut serializer).unwrap();
+
+        let mut deserializer =
+            GarnishDataDeserializer::new_for_value(&mut data, addr).with_128_bit_numbers();
+        assert_eq!(i128::deserialize(&mut deserializer).unwrap(), i128::MIN);
+    }
+
+    #[test]
+    fn serialize_u128_beyond_128_bit_numbers_is_error() {
+        let mut data = WideGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data).with_128_bit_numbers();
+        let result = u128::MAX.serialize(&mut serializer);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_i128_beyond_64_bits_without_128_bit_numbers_is_error() {
+        let mut data = WideGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let result = i128::MAX.serialize(&mut serializer);
+
+        assert!(result.is_err());
+    }
+
+    fn add_source_list(data: &mut SimpleGarnishData, items: &[usize]) -> usize {
+        data.start_list(items.len()).unwrap();
+        for item in items {
+            data.add_to_list(*item, false).unwrap();
+        }
+        data.end_list().unwrap()
+    }
+
+    fn add_source_association(data: &mut SimpleGarnishData, key: &str, value: usize) -> usize {
+        let key = data.parse_add_symbol(key).unwrap();
+        data.add_pair((key, value)).unwrap()
+    }
+
+    fn add_source_associations(data: &mut SimpleGarnishData, pairs: &[usize]) -> usize {
+        data.start_list(pairs.len()).unwrap();
+        for pair in pairs {
+            data.add_to_list(*pair, true).unwrap();
+        }
+        data.end_list().unwrap()
+    }
+
+    #[test]
+    fn transcode_list() {
+        let mut source = SimpleGarnishData::new();
+        let items: Vec<usize> = [10, 20, 30]
+            .iter()
+            .map(|n| source.add_number(SimpleNumber::Integer(*n)).unwrap())
+            .collect();
+        let addr = add_source_list(&mut source, &items);
+
+        let mut destination = UncachedGarnishData::new();
+        let result = transcode(&source, addr, &mut destination).unwrap();
+
+        let value: Vec<i32> = from_garnish_value(&mut destination, result).unwrap();
+        assert_eq!(value, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn transcode_map() {
+        let mut source = SimpleGarnishData::new();
+        let one = source.add_number(SimpleNumber::Integer(1)).unwrap();
+        let one = add_source_association(&mut source, "one", one);
+        let two = source.add_true().unwrap();
+        let two = add_source_association(&mut source, "two", two);
+        let addr = add_source_associations(&mut source, &[one, two]);
+
+        let mut destination = UncachedGarnishData::new();
+        let result = transcode(&source, addr, &mut destination).unwrap();
+
+        assert_eq!(destination.get_list_associations_len(result).unwrap(), 2);
+
+        // map keys are added with add_symbol_from, which doesn't record the symbol's name
+        destination.parse_add_symbol("one").unwrap();
+        destination.parse_add_symbol("two").unwrap();
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct OneTwo {
+            one: i32,
+            two: bool,
+        }
+
+        let value: OneTwo = from_garnish_value(&mut destination, result).unwrap();
+        assert_eq!(value, OneTwo { one: 1, two: true });
+    }
+
+    #[test]
+    fn transcode_nested() {
+        let mut source = SimpleGarnishData::new();
+        let name = source.parse_add_char_list("abc").unwrap();
+        let name = add_source_association(&mut source, "name", name);
+
+        let items: Vec<usize> = [1, 2]
+            .iter()
+            .map(|n| source.add_number(SimpleNumber::Integer(*n)).unwrap())
+            .collect();
+        let values = add_source_list(&mut source, &items);
+        let values = add_source_association(&mut source, "values", values);
+
+        let x = source.add_number(SimpleNumber::Float(1.5)).unwrap();
+        let x = add_source_association(&mut source, "x", x);
+        let inner = add_source_associations(&mut source, &[x]);
+        let inner = add_source_association(&mut source, "inner", inner);
+
+        let addr = add_source_associations(&mut source, &[name, values, inner]);
+
+        let mut destination = UncachedGarnishData::new();
+        let result = transcode(&source, addr, &mut destination).unwrap();
+
+        for key in ["name", "values", "inner", "x"] {
+            destination.parse_add_symbol(key).unwrap();
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Inner {
+            x: f64,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Outer {
+            name: String,
+            values: Vec<i32>,
+            inner: Inner,
+        }
+
+        let value: Outer = from_garnish_value(&mut destination, result).unwrap();
+        assert_eq!(
+            value,
+            Outer {
+                name: "abc".to_string(),
+                values: vec![1, 2],
+                inner: Inner { x: 1.5 },
+            }
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OptionalFields {
+        #[serde(with = "crate::optional::unit_symbol")]
+        symbol: Option<i32>,
+        #[serde(with = "crate::optional::unit_value")]
+        unit: Option<i32>,
+    }
+
+    fn assert_optional_fields_round_trip(behavior: OptionalBehavior) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().optional_behavior(behavior),
+        );
+
+        let value = OptionalFields {
+            symbol: None,
+            unit: None,
+        };
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let symbol = data.get_list_item(addr, 0.into()).unwrap();
+        let (_, symbol) = data.get_pair(symbol).unwrap();
+        assert_eq!(
+            data.get_data().get(symbol).unwrap(),
+            &SimpleData::Symbol(<SimpleGarnishData as GarnishData>::parse_symbol("none").unwrap()),
+            "{:?}",
+            behavior
+        );
+
+        let unit = data.get_list_item(addr, 1.into()).unwrap();
+        let (_, unit) = data.get_pair(unit).unwrap();
+        assert_eq!(
+            data.get_data_type(unit).unwrap(),
+            GarnishDataType::Unit,
+            "{:?}",
+            behavior
+        );
+
+        let result: OptionalFields = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value, "{:?}", behavior);
+    }
+
+    #[test]
+    fn round_trip_per_field_none_encoding() {
+        assert_optional_fields_round_trip(OptionalBehavior::UnitValue);
+        assert_optional_fields_round_trip(OptionalBehavior::UnitSymbol);
+    }
+
+    #[test]
+    fn round_trip_per_field_some() {
+        let mut data = SimpleGarnishData::new();
+        let value = OptionalFields {
+            symbol: Some(1),
+            unit: Some(2),
+        };
+        let addr = to_garnish_data(&value, &mut data).unwrap();
+
+        let result: OptionalFields = from_garnish_value(&mut data, addr).unwrap();
+        assert_eq!(result, value);
+    }
+}
@@ -0,0 +1,110 @@
+//! Helpers for `#[serde(with)]` that choose how a single field's `None` is stored,
+//! regardless of [`crate::GarnishSerializationOptions::optional_behavior`].
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "serde_garnish::optional::unit_symbol")]
+//!     timeout: Option<u32>,
+//!     #[serde(with = "serde_garnish::optional::unit_value")]
+//!     retries: Option<u32>,
+//! }
+//! ```
+
+/// Name `None` is serialized with by [`unit_symbol`], recognized by [`crate::GarnishDataSerializer`]
+/// and [`crate::GarnishDataDeserializer`].
+pub(crate) const GARNISH_NONE_SYMBOL_NAME: &str = "$serde_garnish::NoneSymbol";
+
+/// Store `None` as the symbol `;none`, like [`crate::OptionalBehavior::UnitSymbol`].
+/// The symbol can be changed with [`crate::GarnishSerializationOptions::none_symbol`]
+/// and [`crate::GarnishDeserializationOptions::none_symbol`].
+///
+/// Other serializers see `None` as a unit struct.
+pub mod unit_symbol {
+    use std::fmt::Formatter;
+    use std::marker::PhantomData;
+
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::GARNISH_NONE_SYMBOL_NAME;
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.serialize_some(v),
+            None => serializer.serialize_unit_struct(GARNISH_NONE_SYMBOL_NAME),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_newtype_struct(GARNISH_NONE_SYMBOL_NAME, NoneSymbolVisitor(PhantomData))
+    }
+
+    struct NoneSymbolVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for NoneSymbolVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Option<T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("an optional value")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<T>::deserialize(deserializer)
+        }
+    }
+}
+
+/// Store `None` as Unit, like [`crate::OptionalBehavior::UnitValue`].
+pub mod unit_value {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.serialize_some(v),
+            None => serializer.serialize_unit(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer)
+    }
+}
@@ -1,4 +1,6 @@
 
+use std::cmp::Ordering;
+
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum OptionalBehavior {
     UnitSymbol,
@@ -9,6 +11,9 @@ pub enum OptionalBehavior {
 pub enum StructBehavior {
     ExcludeTyping,
     IncludeTyping,
+    /// Add the type name as an association inside the struct's own list,
+    /// keyed by [`GarnishSerializationOptions::type_tag_key`], instead of wrapping it.
+    IncludeTypingAsPair,
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
@@ -16,12 +21,123 @@ pub enum VariantNameBehavior {
     Short,
     Full,
     Index,
+    /// Like `Short`, stored as a CharList instead of a Symbol.
+    ShortString,
+    /// Like `Full`, stored as a CharList instead of a Symbol.
+    FullString,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum CharSerializationBehavior {
+    Char,
+    CharList,
+    CodePoint,
 }
 
+/// How byte arrays are serialized.
+/// `CharListUtf8` stores bytes holding UTF-8 text as a CharList, failing on invalid UTF-8.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum BytesBehavior {
+    ByteList,
+    CharListUtf8,
+}
+
+/// How floats are serialized.
+/// With `CollapseWholeFloats` a float is stored as an integer when it is finite,
+/// has no fractional part (`f.fract() == 0.0`) and is within the range of `i64`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum NumberFormatBehavior {
+    Exact,
+    CollapseWholeFloats,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum RangeBehavior {
+    Struct,
+    GarnishRange,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum TypeSymbolTiming {
+    Before,
+    After,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum TagPosition {
+    First,
+    Last,
+}
+
+/// How map keys are serialized.
+/// `AlwaysSymbol` converts every key to a symbol, `PreserveType` keeps the key's own value,
+/// so a number key stays a number.
+/// `PreserveNumbers` keeps number keys and converts all others to symbols, matching struct fields,
+/// so a `#[serde(flatten)]` map with number keys can share a list with the struct's fields.
+/// Char keys are kept as serialized under any behavior, so they can be read back as chars.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum MapKeyBehavior {
+    AlwaysSymbol,
+    PreserveType,
+    PreserveNumbers,
+}
+
+/// How sequences and tuples are stored.
+/// `Concatenation` joins the elements with concatenations so they can be appended to others.
+/// Sequences with fewer than 2 elements are still stored as a List.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum SequenceBehavior {
+    List,
+    Concatenation,
+}
+
+/// How structs without any serialized fields are stored.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum EmptyStructBehavior {
+    EmptyList,
+    Unit,
+}
+
+/// Order of the associations in serialized maps and structs.
+/// `Sorted` and `Custom` buffer each association until the list is ended,
+/// comparing keys by their string form.
+/// `Sorted` puts number keys first, in numeric order, followed by all other keys in string order.
+#[derive(Debug, Copy, Clone)]
+pub enum MapKeyOrdering {
+    AsIs,
+    Sorted,
+    Custom(fn(&str, &str) -> Ordering),
+}
+
+#[derive(Debug, Copy, Clone)]
 pub struct GarnishSerializationOptions {
     pub optional_behavior: OptionalBehavior,
     pub struct_typing_behavior: StructBehavior,
     pub variant_name_behavior: VariantNameBehavior,
+    pub deduplicate_values: bool,
+    pub type_symbol_timing: TypeSymbolTiming,
+    pub type_tag_position: TagPosition,
+    pub char_serialization_behavior: CharSerializationBehavior,
+    pub range_behavior: RangeBehavior,
+    pub preserve_option_nesting: bool,
+    pub number_format_behavior: NumberFormatBehavior,
+    pub map_key_ordering: MapKeyOrdering,
+    pub map_key_behavior: MapKeyBehavior,
+    pub json_compat: bool,
+    pub empty_struct_behavior: EmptyStructBehavior,
+    pub detect_lossy_floats: bool,
+    pub type_tag_key: &'static str,
+    pub none_symbol: &'static str,
+    pub compact_numbers: bool,
+    pub sequence_behavior: SequenceBehavior,
+    pub skip_unit_fields: bool,
+    pub bytes_behavior: BytesBehavior,
+}
+
+impl Default for GarnishSerializationOptions {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GarnishSerializationOptions {
@@ -30,6 +146,24 @@ impl GarnishSerializationOptions {
             optional_behavior: OptionalBehavior::UnitValue,
             struct_typing_behavior: StructBehavior::ExcludeTyping,
             variant_name_behavior: VariantNameBehavior::Full,
+            deduplicate_values: false,
+            type_symbol_timing: TypeSymbolTiming::Before,
+            type_tag_position: TagPosition::First,
+            char_serialization_behavior: CharSerializationBehavior::Char,
+            range_behavior: RangeBehavior::Struct,
+            preserve_option_nesting: false,
+            number_format_behavior: NumberFormatBehavior::Exact,
+            map_key_ordering: MapKeyOrdering::AsIs,
+            map_key_behavior: MapKeyBehavior::AlwaysSymbol,
+            json_compat: false,
+            empty_struct_behavior: EmptyStructBehavior::EmptyList,
+            detect_lossy_floats: false,
+            type_tag_key: "__type",
+            none_symbol: "none",
+            compact_numbers: false,
+            sequence_behavior: SequenceBehavior::List,
+            skip_unit_fields: false,
+            bytes_behavior: BytesBehavior::ByteList,
         }
     }
 
@@ -47,4 +181,314 @@ impl GarnishSerializationOptions {
         self.variant_name_behavior = variant_name_behavior;
         self
     }
+
+    pub fn deduplicate_values(mut self, deduplicate_values: bool) -> Self {
+        self.deduplicate_values = deduplicate_values;
+        self
+    }
+
+    pub fn type_symbol_timing(mut self, type_symbol_timing: TypeSymbolTiming) -> Self {
+        self.type_symbol_timing = type_symbol_timing;
+        self
+    }
+
+    pub fn type_tag_position(mut self, type_tag_position: TagPosition) -> Self {
+        self.type_tag_position = type_tag_position;
+        self
+    }
+
+    pub fn char_serialization_behavior(
+        mut self,
+        char_serialization_behavior: CharSerializationBehavior,
+    ) -> Self {
+        self.char_serialization_behavior = char_serialization_behavior;
+        self
+    }
+
+    pub fn range_behavior(mut self, range_behavior: RangeBehavior) -> Self {
+        self.range_behavior = range_behavior;
+        self
+    }
+
+    /// Wrap `Some` values in a list tagged with the symbol `Some`,
+    /// so nested options like `Some(None)` can be told apart from `None`.
+    pub fn preserve_option_nesting(mut self, preserve_option_nesting: bool) -> Self {
+        self.preserve_option_nesting = preserve_option_nesting;
+        self
+    }
+
+    pub fn number_format_behavior(mut self, number_format_behavior: NumberFormatBehavior) -> Self {
+        self.number_format_behavior = number_format_behavior;
+        self
+    }
+
+    pub fn map_key_ordering(mut self, map_key_ordering: MapKeyOrdering) -> Self {
+        self.map_key_ordering = map_key_ordering;
+        self
+    }
+
+    pub fn map_key_behavior(mut self, map_key_behavior: MapKeyBehavior) -> Self {
+        self.map_key_behavior = map_key_behavior;
+        self
+    }
+
+    /// Store every number as a float, like JSON numbers.
+    pub fn json_compat(mut self, json_compat: bool) -> Self {
+        self.json_compat = json_compat;
+        self
+    }
+
+    pub fn empty_struct_behavior(mut self, empty_struct_behavior: EmptyStructBehavior) -> Self {
+        self.empty_struct_behavior = empty_struct_behavior;
+        self
+    }
+
+    /// Read back each serialized float and record the ones not stored exactly,
+    /// available from [`crate::GarnishDataSerializer::lossy_floats`]. Output is unchanged.
+    pub fn detect_lossy_floats(mut self, detect_lossy_floats: bool) -> Self {
+        self.detect_lossy_floats = detect_lossy_floats;
+        self
+    }
+
+    /// Symbol the type name is associated with when using [`StructBehavior::IncludeTypingAsPair`].
+    pub fn type_tag_key(mut self, type_tag_key: &'static str) -> Self {
+        self.type_tag_key = type_tag_key;
+        self
+    }
+
+    /// Symbol `None` is stored as when using [`OptionalBehavior::UnitSymbol`].
+    pub fn none_symbol(mut self, none_symbol: &'static str) -> Self {
+        self.none_symbol = none_symbol;
+        self
+    }
+
+    /// Create integers from the narrowest type that holds their value, instead of the serialized type,
+    /// so data objects with multiple integer widths can store them in less space.
+    /// See [`crate::GarnishNumberConversions::from_narrowest_integer`].
+    pub fn compact_numbers(mut self, compact_numbers: bool) -> Self {
+        self.compact_numbers = compact_numbers;
+        self
+    }
+
+    pub fn sequence_behavior(mut self, sequence_behavior: SequenceBehavior) -> Self {
+        self.sequence_behavior = sequence_behavior;
+        self
+    }
+
+    /// Leave out struct fields whose value is serialized as Unit, such as `()`,
+    /// or `None` when using [`OptionalBehavior::UnitValue`].
+    ///
+    /// Only applies when serializing. Skipped fields are missing from the serialized struct,
+    /// so deserializing it fails with a missing field error unless the field is an `Option`
+    /// or uses `#[serde(default)]`.
+    pub fn skip_unit_fields(mut self, skip_unit_fields: bool) -> Self {
+        self.skip_unit_fields = skip_unit_fields;
+        self
+    }
+
+    pub fn bytes_behavior(mut self, bytes_behavior: BytesBehavior) -> Self {
+        self.bytes_behavior = bytes_behavior;
+        self
+    }
+}
+
+/// Whether a Symbol can be read when a String is requested.
+/// Identifiers, such as field names, always accept symbols.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum SymbolStringBehavior {
+    AllowSymbolAsString,
+    StrictCharList,
+}
+
+/// How a variant name that doesn't match any of an enum's variants is read.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum UnknownVariantBehavior {
+    /// Give the name to the enum as is, which fails unless it has a `#[serde(other)]` variant.
+    Error,
+    /// Read as the named variant instead, with the unknown name as its value.
+    /// Any value stored with the unknown variant is skipped.
+    Capture(&'static str),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GarnishDeserializationOptions {
+    pub variant_tag_position: TagPosition,
+    pub preserve_option_nesting: bool,
+    pub reuse_string_buffer: bool,
+    pub byte_list_as_string: bool,
+    pub optional_behavior: OptionalBehavior,
+    pub json_compat: bool,
+    pub lenient_number_coercion: bool,
+    pub symbol_string_behavior: SymbolStringBehavior,
+    pub max_depth: usize,
+    pub flatten_lists_in_concatenation: bool,
+    pub unknown_variant_behavior: UnknownVariantBehavior,
+    pub none_symbol: &'static str,
+}
+
+impl Default for GarnishDeserializationOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GarnishDeserializationOptions {
+    pub fn new() -> Self {
+        Self {
+            variant_tag_position: TagPosition::First,
+            preserve_option_nesting: false,
+            reuse_string_buffer: false,
+            byte_list_as_string: false,
+            optional_behavior: OptionalBehavior::UnitValue,
+            json_compat: false,
+            lenient_number_coercion: false,
+            symbol_string_behavior: SymbolStringBehavior::AllowSymbolAsString,
+            max_depth: 128,
+            flatten_lists_in_concatenation: false,
+            unknown_variant_behavior: UnknownVariantBehavior::Error,
+            none_symbol: "none",
+        }
+    }
+
+    pub fn variant_tag_position(mut self, variant_tag_position: TagPosition) -> Self {
+        self.variant_tag_position = variant_tag_position;
+        self
+    }
+
+    /// Read lists tagged with the symbol `Some` as the `Some` value of an option.
+    pub fn preserve_option_nesting(mut self, preserve_option_nesting: bool) -> Self {
+        self.preserve_option_nesting = preserve_option_nesting;
+        self
+    }
+
+    /// Convert symbols, for field names and variants, into a single buffer owned by the deserializer
+    /// instead of allocating a new string each time.
+    pub fn reuse_string_buffer(mut self, reuse_string_buffer: bool) -> Self {
+        self.reuse_string_buffer = reuse_string_buffer;
+        self
+    }
+
+    /// Read byte lists as UTF-8 when a String is requested, failing on invalid UTF-8.
+    pub fn byte_list_as_string(mut self, byte_list_as_string: bool) -> Self {
+        self.byte_list_as_string = byte_list_as_string;
+        self
+    }
+
+    /// With [`OptionalBehavior::UnitSymbol`], the symbol `none`, or the one set by
+    /// [`GarnishDeserializationOptions::none_symbol`], is read as `None` in addition to Unit.
+    pub fn optional_behavior(mut self, optional_behavior: OptionalBehavior) -> Self {
+        self.optional_behavior = optional_behavior;
+        self
+    }
+
+    /// Fail on integers beyond 2^53, which JSON numbers can't represent exactly.
+    pub fn json_compat(mut self, json_compat: bool) -> Self {
+        self.json_compat = json_compat;
+        self
+    }
+
+    /// Truncate fractional numbers toward zero when an integer is requested, instead of failing.
+    /// The truncated value must still fit in the requested type.
+    pub fn lenient_number_coercion(mut self, lenient_number_coercion: bool) -> Self {
+        self.lenient_number_coercion = lenient_number_coercion;
+        self
+    }
+
+    pub fn symbol_string_behavior(mut self, symbol_string_behavior: SymbolStringBehavior) -> Self {
+        self.symbol_string_behavior = symbol_string_behavior;
+        self
+    }
+
+    /// Maximum number of nested values, failing instead of recursing further.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Spread the items of lists in a concatenation into the sequence,
+    /// so `(1, 2) <> 3` is read as `[1, 2, 3]` instead of `[[1, 2], 3]`.
+    pub fn flatten_lists_in_concatenation(mut self, flatten_lists_in_concatenation: bool) -> Self {
+        self.flatten_lists_in_concatenation = flatten_lists_in_concatenation;
+        self
+    }
+
+    /// Capture variant names an enum doesn't have into a fallback variant,
+    /// such as `Unknown(String)`, instead of failing.
+    pub fn unknown_variant_behavior(
+        mut self,
+        unknown_variant_behavior: UnknownVariantBehavior,
+    ) -> Self {
+        self.unknown_variant_behavior = unknown_variant_behavior;
+        self
+    }
+
+    /// Symbol read as `None` when using [`OptionalBehavior::UnitSymbol`].
+    pub fn none_symbol(mut self, none_symbol: &'static str) -> Self {
+        self.none_symbol = none_symbol;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use garnish_lang_simple_data::{SimpleData, SimpleGarnishData, SimpleNumber};
+    use garnish_lang_traits::GarnishData;
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        CharSerializationBehavior, GarnishDataDeserializer, GarnishDataSerializer,
+        GarnishDeserializationOptions, GarnishSerializationOptions,
+    };
+
+    #[test]
+    fn serialization_options_default_matches_new() {
+        // debug output lists every field
+        assert_eq!(
+            format!("{:?}", GarnishSerializationOptions::default()),
+            format!("{:?}", GarnishSerializationOptions::new())
+        );
+    }
+
+    #[test]
+    fn serialization_options_reused_for_several_serializers() {
+        let options = GarnishSerializationOptions::new()
+            .char_serialization_behavior(CharSerializationBehavior::CharList);
+        // options are Copy, so the original stays usable
+        let copy = options;
+
+        for options in [options, copy] {
+            let mut data = SimpleGarnishData::new();
+            let mut serializer = GarnishDataSerializer::new_with_options(&mut data, options);
+            let addr = 'x'.serialize(&mut serializer).unwrap();
+
+            assert_eq!(
+                data.get_data().get(addr).unwrap(),
+                &SimpleData::CharList("x".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn deserialization_options_default_matches_new() {
+        assert_eq!(
+            format!("{:?}", GarnishDeserializationOptions::default()),
+            format!("{:?}", GarnishDeserializationOptions::new())
+        );
+    }
+
+    #[test]
+    fn deserialization_options_reused_for_several_deserializers() {
+        let options = GarnishDeserializationOptions::new().lenient_number_coercion(true);
+        // options are Copy, so the original stays usable
+        let copy = options;
+
+        for options in [options, copy] {
+            let mut data = SimpleGarnishData::new();
+            let addr = data.add_number(SimpleNumber::Float(1.5)).unwrap();
+            let mut deserializer =
+                GarnishDataDeserializer::new_for_value_with_options(&mut data, addr, options);
+
+            assert_eq!(i32::deserialize(&mut deserializer).unwrap(), 1);
+        }
+    }
 }
\ No newline at end of file
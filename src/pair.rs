@@ -0,0 +1,27 @@
+use serde::ser::SerializeTupleStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Name [`GarnishPair`] is serialized with, recognized by [`crate::GarnishDataSerializer`].
+pub(crate) const GARNISH_PAIR_NAME: &str = "$serde_garnish::GarnishPair";
+
+/// A key and value that serializes to a single Garnish Pair instead of a list.
+///
+/// Like map keys, the left side is converted to a symbol.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GarnishPair<K, V>(pub K, pub V);
+
+impl<K, V> Serialize for GarnishPair<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple_struct(GARNISH_PAIR_NAME, 2)?;
+        s.serialize_field(&self.0)?;
+        s.serialize_field(&self.1)?;
+        s.end()
+    }
+}
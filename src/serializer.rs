@@ -1,17 +1,80 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+
 use serde::ser::{
     SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
     SerializeTupleStruct, SerializeTupleVariant,
 };
 use serde::{Serialize, Serializer};
 
-use garnish_lang_traits::{GarnishData, TypeConstants};
+use garnish_lang_traits::{GarnishData, GarnishDataType, TypeConstants};
 
+use crate::capture::{Leaf, LeafCapture};
 use crate::error::{wrap_err, GarnishSerializationError};
+use crate::optional::GARNISH_NONE_SYMBOL_NAME;
+use crate::pair::GARNISH_PAIR_NAME;
+use crate::traits::Number128;
 use crate::{
-    GarnishNumberConversions, GarnishSerializationOptions, OptionalBehavior, StructBehavior,
-    VariantNameBehavior,
+    BytesBehavior, CharSerializationBehavior, EmptyStructBehavior, GarnishNumber128Conversions,
+    GarnishNumberConversions, GarnishSerializationOptions, MapKeyBehavior, MapKeyOrdering,
+    NumberFormatBehavior, OptionalBehavior, RangeBehavior, SequenceBehavior, StructBehavior,
+    TagPosition, TypeSymbolTiming, VariantNameBehavior,
 };
 
+/// A float that the data object didn't store exactly,
+/// recorded when [`GarnishSerializationOptions::detect_lossy_floats`] is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LossyFloat<Size> {
+    /// Address of the stored number.
+    pub addr: Size,
+    /// Value that was serialized.
+    pub value: f64,
+    /// Value read back from data.
+    pub stored: f64,
+}
+
+// order used by MapKeyOrdering::Sorted
+// number keys come first, in numeric order, followed by all other keys in string order
+fn compare_key_names(a: &str, b: &str) -> Ordering {
+    let number = |s: &str| s.parse::<f64>().ok().filter(|f| f.is_finite());
+    match (number(a), number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y).then_with(|| a.cmp(b)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.cmp(b),
+    }
+}
+
+/// Content of a leaf value used to find previously added values
+/// when [`GarnishSerializationOptions::deduplicate_values`] is enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum CachedValue {
+    Integer(i128),
+    Float(u64),
+    Char(char),
+    CharList(String),
+    ByteList(Vec<u8>),
+    Symbol(String),
+}
+
+/// Fields of a `Range` or `RangeInclusive` struct
+/// being serialized with [`RangeBehavior::GarnishRange`].
+struct PendingRange<Size> {
+    name: &'static str,
+    len: usize,
+    inclusive: bool,
+    start: Option<Size>,
+    end: Option<Size>,
+}
+
+/// Tag of an open struct-like value.
+/// The type name is kept until the value ends when using [`TypeSymbolTiming::After`].
+enum StructTag<Size> {
+    Symbol(Size),
+    Name(&'static str),
+}
+
 pub struct GarnishDataSerializer<'a, Data>
 where
     Data: GarnishData,
@@ -22,8 +85,30 @@ where
 {
     data: &'a mut Data,
     data_addr: Option<Data::Size>,
-    struct_sym: Option<Data::Size>,
+    // tag of each open struct-like value, added around its list when it ends
+    struct_tags: Vec<Option<StructTag<Data::Size>>>,
     pending_key: Option<Data::Size>,
+    range: Option<PendingRange<Data::Size>>,
+    // left and right of a GarnishPair being serialized
+    pair: Option<Vec<Data::Size>>,
+    // key name of the map entry being serialized, only set when associations are buffered
+    pending_key_name: Option<String>,
+    // associations of each open map or struct waiting to be ordered
+    association_buffers: Vec<Vec<(String, Data::Size)>>,
+    // declared length and elements serialized of each open tuple
+    tuple_lens: Vec<(usize, usize)>,
+    // elements of each open sequence being joined with concatenations, None for ones built as lists
+    sequence_items: Vec<Option<Vec<Data::Size>>>,
+    // next sequence is an element of a concatenation and must be a list to not be merged into it
+    in_concatenation: bool,
+    // address of the last serialized char, to recognize char map keys
+    char_addr: Option<Data::Size>,
+    // names of symbols added while serializing a key whose name orders associations
+    key_symbols: Option<Vec<(Data::Size, String)>>,
+    lossy_floats: Vec<LossyFloat<Data::Size>>,
+    value_cache: HashMap<CachedValue, Data::Size>,
+    // set when numbers hold integers beyond 64 bits
+    number_128: Option<Number128<Data::Number>>,
     options: GarnishSerializationOptions,
 }
 
@@ -36,29 +121,101 @@ where
     Data::Byte: From<u8>,
 {
     pub fn new(data: &'a mut Data) -> Self {
-        GarnishDataSerializer {
-            data,
-            data_addr: None,
-            options: GarnishSerializationOptions::new(),
-            struct_sym: None,
-            pending_key: None,
-        }
+        Self::new_with_options(data, GarnishSerializationOptions::new())
     }
 
     pub fn new_with_options(data: &'a mut Data, options: GarnishSerializationOptions) -> Self {
         Self {
             data,
             data_addr: None,
-            struct_sym: None,
+            struct_tags: vec![],
             pending_key: None,
+            range: None,
+            pair: None,
+            pending_key_name: None,
+            association_buffers: vec![],
+            tuple_lens: vec![],
+            sequence_items: vec![],
+            in_concatenation: false,
+            char_addr: None,
+            key_symbols: None,
+            lossy_floats: vec![],
+            value_cache: HashMap::new(),
+            number_128: None,
             options,
         }
     }
 
+    /// Serialize `i128` and `u128` values beyond 64 bits, for data whose numbers can hold them.
+    /// Without this those values fail to serialize.
+    pub fn with_128_bit_numbers(self) -> Self
+    where
+        Data::Number: GarnishNumber128Conversions,
+    {
+        Self {
+            number_128: Some(Number128::new()),
+            ..self
+        }
+    }
+
+    /// Address of the most recently completed value.
+    /// After serializing a top level value, this is the address of that value.
     pub fn data_addr(&self) -> Option<Data::Size> {
         self.data_addr
     }
 
+    /// Clear the state left by a previous value, so the next value serializes independently of it,
+    /// even when the previous one failed part way.
+    ///
+    /// Data is shared between values and is left as is, along with the options,
+    /// values cached by [`GarnishSerializationOptions::deduplicate_values`] and recorded lossy floats.
+    pub fn reset(&mut self) {
+        self.data_addr = None;
+        self.struct_tags.clear();
+        self.pending_key = None;
+        self.range = None;
+        self.pair = None;
+        self.pending_key_name = None;
+        self.association_buffers.clear();
+        self.tuple_lens.clear();
+        self.sequence_items.clear();
+        self.in_concatenation = false;
+        self.char_addr = None;
+        self.key_symbols = None;
+    }
+
+    /// Floats that didn't read back from data exactly as serialized.
+    /// Only recorded when [`GarnishSerializationOptions::detect_lossy_floats`] is enabled.
+    pub fn lossy_floats(&self) -> &[LossyFloat<Data::Size>] {
+        &self.lossy_floats
+    }
+
+    /// Create a sequence from addresses already in data, without serializing any values.
+    /// Stored the same way as serialized sequences, following [`GarnishSerializationOptions::sequence_behavior`].
+    pub fn serialize_raw_list(
+        &mut self,
+        addrs: &[Data::Size],
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        match self.options.sequence_behavior {
+            SequenceBehavior::List => self.add_list(addrs),
+            SequenceBehavior::Concatenation => self.add_concatenated(addrs),
+        }
+    }
+
+    fn add_list(
+        &mut self,
+        addrs: &[Data::Size],
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        self.data
+            .start_list(Data::Size::from(addrs.len()))
+            .or_else(wrap_err)?;
+        for addr in addrs {
+            self.data.add_to_list(*addr, false).or_else(wrap_err)?;
+        }
+        let addr = self.data.end_list().or_else(wrap_err);
+        self.completed(addr)
+    }
+
     pub fn add_convertible_number<T>(
         &mut self,
         v: T,
@@ -71,23 +228,564 @@ where
             .or_else(wrap_err)
     }
 
-    fn end_struct_like(&mut self) -> Result<Data::Size, GarnishSerializationError<Data>> {
-        let list_addr = self.data.end_list().or_else(wrap_err)?;
+    // remember the most recently completed value for data_addr
+    fn completed(
+        &mut self,
+        addr: Result<Data::Size, GarnishSerializationError<Data>>,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        if let Ok(addr) = addr.as_ref() {
+            self.data_addr = Some(*addr);
+        }
+        addr
+    }
 
-        match self.struct_sym {
-            Some(addr) => {
-                self.data
-                    .start_list(Data::Size::from(2))
+    fn add_unit(&mut self) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        let addr = self.data.add_unit().or_else(wrap_err);
+        self.completed(addr)
+    }
+
+    fn add_deduplicated<KeyF, AddF>(
+        &mut self,
+        key: KeyF,
+        add: AddF,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>>
+    where
+        KeyF: FnOnce() -> CachedValue,
+        AddF: FnOnce(&mut Self) -> Result<Data::Size, GarnishSerializationError<Data>>,
+    {
+        if !self.options.deduplicate_values {
+            let addr = add(self);
+            return self.completed(addr);
+        }
+
+        let key = key();
+        let addr = match self.value_cache.get(&key) {
+            Some(addr) => *addr,
+            None => {
+                let addr = add(self)?;
+                self.value_cache.insert(key, addr);
+                addr
+            }
+        };
+        self.completed(Ok(addr))
+    }
+
+    fn add_integer<T>(&mut self, v: T) -> Result<Data::Size, GarnishSerializationError<Data>>
+    where
+        T: Into<i128> + Copy,
+        Data::Number: From<T>,
+    {
+        if self.options.json_compat {
+            let f = v.into() as f64;
+            return self.add_deduplicated(
+                || CachedValue::Float(f.to_bits()),
+                |s| s.add_convertible_number(f),
+            );
+        }
+
+        if self.options.compact_numbers {
+            return self.add_deduplicated(
+                || CachedValue::Integer(v.into()),
+                |s| {
+                    let n = Data::Number::from_narrowest_integer(v.into())
+                        .unwrap_or(Data::Number::from(v));
+                    s.add_exact_integer(n, v.into())
+                },
+            );
+        }
+
+        self.add_deduplicated(
+            || CachedValue::Integer(v.into()),
+            |s| s.add_exact_integer(Data::Number::from(v), v.into()),
+        )
+    }
+
+    // integers beyond 64 bits, only created when numbers have GarnishNumber128Conversions
+    fn add_integer_128<T>(
+        &mut self,
+        v: T,
+        magnitude: f64,
+        from: fn(T) -> Data::Number,
+        into: fn(Data::Number) -> T,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>>
+    where
+        T: PartialEq + Display + Copy,
+    {
+        if self.options.json_compat {
+            return self.add_deduplicated(
+                || CachedValue::Float(magnitude.to_bits()),
+                |s| s.add_convertible_number(magnitude),
+            );
+        }
+
+        let n = from(v);
+        // wrapped values can convert back to the same bits, their magnitude won't match
+        if into(n) != v || Into::<f64>::into(n) != magnitude {
+            return Err(GarnishSerializationError::from(
+                format!(
+                    "Integer value {} is out of range for this data's numbers.",
+                    v
+                )
+                .as_str(),
+            ));
+        }
+
+        let addr = self.data.add_number(n).or_else(wrap_err);
+        self.completed(addr)
+    }
+
+    // numbers narrower than the value would silently truncate it
+    fn add_exact_integer(
+        &mut self,
+        n: Data::Number,
+        v: i128,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        if !n.holds_integer(v) {
+            return Err(GarnishSerializationError::from(
+                format!(
+                    "Integer value {} is out of range for this data's numbers.",
+                    v
+                )
+                .as_str(),
+            ));
+        }
+
+        self.data.add_number(n).or_else(wrap_err)
+    }
+
+    fn add_float<T>(&mut self, v: T) -> Result<Data::Size, GarnishSerializationError<Data>>
+    where
+        T: Into<f64> + Copy,
+        Data::Number: From<T>,
+    {
+        let f: f64 = v.into();
+        let addr = if !self.options.json_compat
+            && self.options.number_format_behavior == NumberFormatBehavior::CollapseWholeFloats
+            && f.is_finite()
+            && f.fract() == 0.0
+            && f >= i64::MIN as f64
+            && f < i64::MAX as f64
+            && Data::Number::from(f as i64).holds_integer(f as i128)
+        {
+            self.add_integer(f as i64)?
+        } else {
+            self.add_deduplicated(
+                || CachedValue::Float(v.into().to_bits()),
+                |s| s.add_convertible_number(v),
+            )?
+        };
+
+        if self.options.detect_lossy_floats {
+            let stored: f64 = self.data.get_number(addr).or_else(wrap_err)?.into();
+            if stored.to_bits() != f.to_bits() && !(stored.is_nan() && f.is_nan()) {
+                self.lossy_floats.push(LossyFloat {
+                    addr,
+                    value: f,
+                    stored,
+                });
+            }
+        }
+
+        Ok(addr)
+    }
+
+    fn add_symbol(&mut self, name: &str) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        let addr = self.add_deduplicated(
+            || CachedValue::Symbol(name.to_string()),
+            |s| s.data.parse_add_symbol(name).or_else(wrap_err),
+        )?;
+
+        if let Some(symbols) = self.key_symbols.as_mut() {
+            symbols.push((addr, name.to_string()));
+        }
+
+        Ok(addr)
+    }
+
+    fn start_struct_typing(
+        &mut self,
+        name: &'static str,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        match self.options.type_symbol_timing {
+            TypeSymbolTiming::Before => {
+                let sym = self.add_symbol(name)?;
+                self.struct_tags.push(Some(StructTag::Symbol(sym)));
+            }
+            TypeSymbolTiming::After => self.struct_tags.push(Some(StructTag::Name(name))),
+        }
+
+        Ok(())
+    }
+
+    fn start_struct(
+        &mut self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        match self.options.struct_typing_behavior {
+            StructBehavior::IncludeTyping => self.start_struct_typing(name)?,
+            StructBehavior::IncludeTypingAsPair => {
+                self.struct_tags.push(None);
+                self.start_associations();
+                self.start_list(len + 1)?;
+                return self.add_struct_typing_pair(name);
+            }
+            StructBehavior::ExcludeTyping => self.struct_tags.push(None),
+        }
+        self.start_associations();
+        self.start_list(len)
+    }
+
+    fn start_list(&mut self, len: usize) -> Result<(), GarnishSerializationError<Data>> {
+        self.in_concatenation = false;
+        self.data
+            .start_list(Data::Size::from(len))
+            .or_else(wrap_err)
+    }
+
+    fn add_sequence_item(
+        &mut self,
+        addr: Data::Size,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        match self.sequence_items.last_mut() {
+            Some(Some(items)) => {
+                items.push(addr);
+                Ok(())
+            }
+            _ => self.data.add_to_list(addr, false).or_else(wrap_err),
+        }
+    }
+
+    fn end_sequence(&mut self) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        let items = match self.sequence_items.pop() {
+            Some(Some(items)) => items,
+            _ => {
+                let addr = self.data.end_list().or_else(wrap_err);
+                return self.completed(addr);
+            }
+        };
+
+        self.add_concatenated(&items)
+    }
+
+    fn add_concatenated(
+        &mut self,
+        items: &[Data::Size],
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        // a concatenation needs at least 2 items
+        let addr = match items {
+            [first, second, rest @ ..] => {
+                let mut addr = self
+                    .data
+                    .add_concatenation(*first, *second)
                     .or_else(wrap_err)?;
+                for item in rest {
+                    addr = self.data.add_concatenation(addr, *item).or_else(wrap_err)?;
+                }
+                addr
+            }
+            _ => return self.add_list(items),
+        };
+
+        self.completed(Ok(addr))
+    }
+
+    // type name as the first association of the open list, ahead of any buffered associations
+    fn add_struct_typing_pair(
+        &mut self,
+        name: &'static str,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        let key = self.add_symbol(self.options.type_tag_key)?;
+        let value = self.add_symbol(name)?;
+        let pair = self.data.add_pair((key, value)).or_else(wrap_err)?;
+
+        self.data.add_to_list(pair, true).or_else(wrap_err)
+    }
+
+    fn buffers_associations(&self) -> bool {
+        !matches!(self.options.map_key_ordering, MapKeyOrdering::AsIs)
+    }
 
-                self.data.add_to_list(addr, false).or_else(wrap_err)?;
-                self.data.add_to_list(list_addr, false).or_else(wrap_err)?;
+    fn start_associations(&mut self) {
+        if self.buffers_associations() {
+            self.association_buffers.push(vec![]);
+        }
+    }
 
-                self.data.end_list().or_else(wrap_err)
+    fn add_association(
+        &mut self,
+        name: Option<String>,
+        pair: Data::Size,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        match (self.association_buffers.last_mut(), name) {
+            (Some(buffer), Some(name)) => {
+                buffer.push((name, pair));
+                Ok(())
             }
+            _ => self.data.add_to_list(pair, true).or_else(wrap_err),
+        }
+    }
+
+    fn end_associations(&mut self) -> Result<(), GarnishSerializationError<Data>> {
+        if !self.buffers_associations() {
+            return Ok(());
+        }
+
+        let mut buffer = self.association_buffers.pop().unwrap_or_default();
+        match self.options.map_key_ordering {
+            MapKeyOrdering::AsIs => (),
+            MapKeyOrdering::Sorted => buffer.sort_by(|a, b| compare_key_names(&a.0, &b.0)),
+            MapKeyOrdering::Custom(compare) => buffer.sort_by(|a, b| compare(&a.0, &b.0)),
+        }
+
+        for (_, pair) in buffer {
+            self.data.add_to_list(pair, true).or_else(wrap_err)?;
+        }
+
+        Ok(())
+    }
+
+    fn end_struct_like(
+        &mut self,
+        empty_behavior: EmptyStructBehavior,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        let mut list_addr = self.data.end_list().or_else(wrap_err)?;
+
+        if empty_behavior == EmptyStructBehavior::Unit
+            && self.data.get_list_len(list_addr).or_else(wrap_err)? == Data::Size::zero()
+        {
+            list_addr = self.data.add_unit().or_else(wrap_err)?;
+        }
+
+        let tag = match self.struct_tags.pop().flatten() {
+            Some(StructTag::Symbol(sym)) => Some(sym),
+            // type symbol is added after fields when using TypeSymbolTiming::After
+            Some(StructTag::Name(name)) => Some(self.add_symbol(name)?),
+            None => None,
+        };
+
+        match tag {
+            Some(addr) => self.add_tagged(addr, list_addr),
             // excluding name
-            None => Ok(list_addr),
+            None => self.completed(Ok(list_addr)),
+        }
+    }
+
+    fn add_tagged(
+        &mut self,
+        tag: Data::Size,
+        value: Data::Size,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>> {
+        self.data
+            .start_list(Data::Size::from(2))
+            .or_else(wrap_err)?;
+
+        let (first, second) = match self.options.type_tag_position {
+            TagPosition::First => (tag, value),
+            TagPosition::Last => (value, tag),
+        };
+
+        self.data.add_to_list(first, false).or_else(wrap_err)?;
+        self.data.add_to_list(second, false).or_else(wrap_err)?;
+
+        let addr = self.data.end_list().or_else(wrap_err);
+        self.completed(addr)
+    }
+}
+
+impl<'a, Data> GarnishDataSerializer<'a, Data>
+where
+    Data: GarnishData,
+    Data::Number: GarnishNumberConversions,
+    Data::Size: From<usize>,
+    Data::Char: From<char>,
+    Data::Char: Into<char>,
+    Data::Byte: From<u8>,
+{
+    // string form of a map key, used to order buffered associations
+    // built without adding to data, symbols are named from those recorded while serializing the key
+    fn key_name(
+        &self,
+        addr: Data::Size,
+        symbols: &[(Data::Size, String)],
+    ) -> Result<String, GarnishSerializationError<Data>> {
+        let mut s = String::new();
+        self.write_key_name(addr, symbols, 0, &mut s)?;
+        Ok(s)
+    }
+
+    fn write_key_name(
+        &self,
+        addr: Data::Size,
+        symbols: &[(Data::Size, String)],
+        depth: usize,
+        s: &mut String,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        match self.data.get_data_type(addr).or_else(wrap_err)? {
+            GarnishDataType::CharList => {
+                let len = self.data.get_char_list_len(addr).or_else(wrap_err)?;
+                let mut i = Data::Size::zero();
+                while i < len {
+                    let c = self
+                        .data
+                        .get_char_list_item(addr, Data::size_to_number(i))
+                        .or_else(wrap_err)?;
+                    s.push(c.into());
+                    i += Data::Size::one();
+                }
+            }
+            GarnishDataType::Char => s.push(self.data.get_char(addr).or_else(wrap_err)?.into()),
+            GarnishDataType::Number => {
+                s.push_str(&self.data.get_number(addr).or_else(wrap_err)?.to_string())
+            }
+            GarnishDataType::Byte => {
+                s.push_str(&self.data.get_byte(addr).or_else(wrap_err)?.to_string())
+            }
+            GarnishDataType::Symbol => match symbols.iter().find(|(a, _)| *a == addr) {
+                Some((_, name)) => s.push_str(name),
+                None => s.push_str(&self.data.get_symbol(addr).or_else(wrap_err)?.to_string()),
+            },
+            GarnishDataType::Unit => s.push_str("()"),
+            GarnishDataType::True => s.push_str("$?"),
+            GarnishDataType::False => s.push_str("$!"),
+            GarnishDataType::Pair => {
+                let (left, right) = self.data.get_pair(addr).or_else(wrap_err)?;
+                self.write_key_name(left, symbols, depth + 1, s)?;
+                s.push_str(" = ");
+                self.write_key_name(right, symbols, depth + 1, s)?;
+            }
+            GarnishDataType::List => {
+                let len = self.data.get_list_len(addr).or_else(wrap_err)?;
+                if depth > 0 {
+                    s.push('(');
+                }
+
+                let mut i = Data::Size::zero();
+                while i < len {
+                    if i > Data::Size::zero() {
+                        s.push_str(", ");
+                    }
+                    let item = self
+                        .data
+                        .get_list_item(addr, Data::size_to_number(i))
+                        .or_else(wrap_err)?;
+                    self.write_key_name(item, symbols, depth + 1, s)?;
+                    i += Data::Size::one();
+                }
+
+                if depth > 0 {
+                    s.push(')');
+                }
+            }
+            t => s.push_str(&format!("{:?}", t)),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_sequence_element<T>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), GarnishSerializationError<Data>>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.in_concatenation = matches!(self.sequence_items.last(), Some(Some(_)));
+        let addr = value.serialize(&mut *self)?;
+        self.in_concatenation = false;
+        self.add_sequence_item(addr)
+    }
+
+    fn serialize_struct_field<T>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), GarnishSerializationError<Data>>
+    where
+        T: Serialize + ?Sized,
+    {
+        // checked before serializing so skipped fields don't add a Unit to data
+        if self.options.skip_unit_fields
+            && LeafCapture::capture(value, &self.options) == Some(Leaf::Unit)
+        {
+            return Ok(());
         }
+
+        let v = value.serialize(&mut *self)?;
+        // structs without fields can still end as Unit with EmptyStructBehavior::Unit
+        if self.options.skip_unit_fields
+            && self.data.get_data_type(v).or_else(wrap_err)? == GarnishDataType::Unit
+        {
+            return Ok(());
+        }
+
+        self.add_struct_field(key, v)
+    }
+
+    fn serialize_range_end<T>(
+        &mut self,
+        inclusive: bool,
+        value: &T,
+    ) -> Result<Data::Size, GarnishSerializationError<Data>>
+    where
+        T: Serialize + ?Sized,
+    {
+        if inclusive {
+            return value.serialize(&mut *self);
+        }
+
+        // garnish ranges are inclusive on both ends,
+        // read the end without adding it so only the previous integer is stored
+        let end = match LeafCapture::capture(value, &self.options) {
+            Some(Leaf::Integer(i)) => i,
+            Some(Leaf::Float(f)) if f.is_finite() && f.fract() == 0.0 => f as i128,
+            _ => Err(GarnishSerializationError::from(
+                "Exclusive Range end must be an integer to convert to a Garnish Range.",
+            ))?,
+        };
+
+        let last = end.checked_sub(1);
+        if let Some(v) = last.and_then(|v| i64::try_from(v).ok()) {
+            return self.add_integer(v);
+        }
+        if let Some(v) = last.and_then(|v| u64::try_from(v).ok()) {
+            return self.add_integer(v);
+        }
+
+        match (last, self.number_128) {
+            (Some(v), Some(wide)) => {
+                self.add_integer_128(v, v as f64, wide.from_i128, wide.into_i128)
+            }
+            _ => Err(GarnishSerializationError::from(
+                format!(
+                    "Exclusive Range end {} has no previous integer to convert to a Garnish Range.",
+                    end
+                )
+                .as_str(),
+            )),
+        }
+    }
+
+    // fields of a struct named like a range didn't match, start it as a regular struct instead
+    fn start_range_as_struct(
+        &mut self,
+        range: PendingRange<Data::Size>,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        self.start_struct(range.name, range.len)?;
+        match range.start {
+            Some(start) => self.add_struct_field("start", start),
+            None => Ok(()),
+        }
+    }
+
+    fn add_struct_field(
+        &mut self,
+        key: &'static str,
+        v: Data::Size,
+    ) -> Result<(), GarnishSerializationError<Data>> {
+        let k = self.add_symbol(key)?;
+        let p = self.data.add_pair((k, v)).or_else(wrap_err)?;
+        self.add_association(Some(key.to_string()), p)
     }
 }
 
@@ -112,82 +810,137 @@ where
     type SerializeStructVariant = Self;
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
-        match v {
+        let addr = match v {
             true => self.data.add_true().or_else(wrap_err),
             false => self.data.add_false().or_else(wrap_err),
-        }
+        };
+        self.completed(addr)
     }
 
     fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
     }
 
     fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_integer(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        match (i64::try_from(v), u64::try_from(v), self.number_128) {
+            (Ok(v), _, _) => self.add_integer(v),
+            (_, Ok(v), _) => self.add_integer(v),
+            (Err(_), Err(_), Some(wide)) => {
+                self.add_integer_128(v, v as f64, wide.from_i128, wide.into_i128)
+            }
+            (Err(_), Err(_), None) => Err(GarnishSerializationError::from(
+                format!("i128 value {} is out of range for Garnish numbers.", v).as_str(),
+            )),
+        }
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        match (u64::try_from(v), self.number_128) {
+            (Ok(v), _) => self.add_integer(v),
+            (Err(_), Some(wide)) => {
+                self.add_integer_128(v, v as f64, wide.from_u128, wide.into_u128)
+            }
+            (Err(_), None) => Err(GarnishSerializationError::from(
+                format!("u128 value {} is out of range for Garnish numbers.", v).as_str(),
+            )),
+        }
     }
 
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_float(v)
     }
 
     fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
-        self.add_convertible_number(v)
+        self.add_float(v)
     }
 
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
-        self.data.add_char(Data::Char::from(v)).or_else(wrap_err)
+        let addr = match self.options.char_serialization_behavior {
+            CharSerializationBehavior::Char => self.add_deduplicated(
+                || CachedValue::Char(v),
+                |s| s.data.add_char(Data::Char::from(v)).or_else(wrap_err),
+            ),
+            CharSerializationBehavior::CharList => self.serialize_str(v.encode_utf8(&mut [0; 4])),
+            CharSerializationBehavior::CodePoint => self.add_integer(v as u32),
+        }?;
+
+        self.char_addr = Some(addr);
+        Ok(addr)
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        self.data.start_char_list().or_else(wrap_err)?;
-        for c in v.chars() {
-            self.data
-                .add_to_char_list(Data::Char::from(c))
-                .or_else(wrap_err)?;
-        }
-
-        self.data.end_char_list().or_else(wrap_err)
+        self.add_deduplicated(
+            || CachedValue::CharList(v.to_string()),
+            |s| {
+                s.data.start_char_list().or_else(wrap_err)?;
+                for c in v.chars() {
+                    s.data
+                        .add_to_char_list(Data::Char::from(c))
+                        .or_else(wrap_err)?;
+                }
+
+                s.data.end_char_list().or_else(wrap_err)
+            },
+        )
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        self.data.start_byte_list().or_else(wrap_err)?;
-        for b in v {
-            self.data
-                .add_to_byte_list(Data::Byte::from(*b))
-                .or_else(wrap_err)?;
+        if self.options.bytes_behavior == BytesBehavior::CharListUtf8 {
+            let s = std::str::from_utf8(v).map_err(|e| {
+                GarnishSerializationError::from(
+                    format!("Bytes are not valid UTF-8: {}", e).as_str(),
+                )
+            })?;
+            return self.serialize_str(s);
         }
 
-        self.data.end_byte_list().or_else(wrap_err)
+        self.add_deduplicated(
+            || CachedValue::ByteList(v.to_vec()),
+            |s| {
+                s.data.start_byte_list().or_else(wrap_err)?;
+                for b in v {
+                    s.data
+                        .add_to_byte_list(Data::Byte::from(*b))
+                        .or_else(wrap_err)?;
+                }
+
+                s.data.end_byte_list().or_else(wrap_err)
+            },
+        )
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
         match self.options.optional_behavior {
-            OptionalBehavior::UnitSymbol => self.data.parse_add_symbol("none").or_else(wrap_err),
-            OptionalBehavior::UnitValue => self.data.add_unit().or_else(wrap_err),
+            OptionalBehavior::UnitSymbol => self.add_symbol(self.options.none_symbol),
+            OptionalBehavior::UnitValue => self.add_unit(),
         }
     }
 
@@ -195,24 +948,42 @@ where
     where
         T: Serialize,
     {
-        value.serialize(&mut *self)
+        match self.options.preserve_option_nesting {
+            true => {
+                let tag = self.add_symbol("Some")?;
+                let value = value.serialize(&mut *self)?;
+                self.add_tagged(tag, value)
+            }
+            false => value.serialize(&mut *self),
+        }
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        self.data.add_unit().or_else(wrap_err)
+        self.add_unit()
     }
 
     fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        // None from optional::unit_symbol
+        if name == GARNISH_NONE_SYMBOL_NAME {
+            return self.add_symbol(self.options.none_symbol);
+        }
+
         match self.options.struct_typing_behavior {
-            StructBehavior::ExcludeTyping => self.data.add_unit().or_else(wrap_err),
+            StructBehavior::ExcludeTyping => self.add_unit(),
             StructBehavior::IncludeTyping => {
-                let name_addr = self.data.parse_add_symbol(name).or_else(wrap_err)?;
-                let v = self.data.add_unit().or_else(wrap_err)?;
+                let name_addr = self.add_symbol(name)?;
+                let v = self.add_unit()?;
+
+                self.add_tagged(name_addr, v)
+            }
+            StructBehavior::IncludeTypingAsPair => {
+                self.data
+                    .start_list(Data::Size::from(1))
+                    .or_else(wrap_err)?;
+                self.add_struct_typing_pair(name)?;
 
-                self.data.start_list(Data::Size::one()).or_else(wrap_err)?;
-                self.data.add_to_list(name_addr, false).or_else(wrap_err)?;
-                self.data.add_to_list(v, false).or_else(wrap_err)?;
-                self.data.end_list().or_else(wrap_err)
+                let addr = self.data.end_list().or_else(wrap_err);
+                self.completed(addr)
             }
         }
     }
@@ -224,15 +995,13 @@ where
         variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
         match self.options.variant_name_behavior {
-            VariantNameBehavior::Short => self.data.parse_add_symbol(variant).or_else(wrap_err),
-            VariantNameBehavior::Full => self
-                .data
-                .parse_add_symbol(format!("{}::{}", name, variant).as_str())
-                .or_else(wrap_err),
-            VariantNameBehavior::Index => self
-                .data
-                .add_number(Data::Number::from(variant_index))
-                .or_else(wrap_err),
+            VariantNameBehavior::Short => self.add_symbol(variant),
+            VariantNameBehavior::Full => self.add_symbol(format!("{}::{}", name, variant).as_str()),
+            VariantNameBehavior::Index => self.add_integer(variant_index),
+            VariantNameBehavior::ShortString => self.serialize_str(variant),
+            VariantNameBehavior::FullString => {
+                self.serialize_str(format!("{}::{}", name, variant).as_str())
+            }
         }
     }
 
@@ -257,25 +1026,28 @@ where
     where
         T: Serialize,
     {
-        self.data.start_list(Data::Size::one()).or_else(wrap_err)?;
-
         let sym = self.serialize_unit_variant(name, variant_index, variant)?;
         let value = value.serialize(&mut *self)?;
 
-        self.data.add_to_list(sym, false).or_else(wrap_err)?;
-        self.data.add_to_list(value, false).or_else(wrap_err)?;
-
-        self.data.end_list().or_else(wrap_err)
+        self.add_tagged(sym, value)
     }
 
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        self.data
-            .start_list(Data::Size::from(len.unwrap_or(0)))
-            .or_else(wrap_err)?;
+        // concatenations of concatenations are merged, so nested sequences are lists
+        if self.options.sequence_behavior == SequenceBehavior::Concatenation
+            && !self.in_concatenation
+        {
+            self.sequence_items
+                .push(Some(Vec::with_capacity(len.unwrap_or(0))));
+        } else {
+            self.start_list(len.unwrap_or(0))?;
+            self.sequence_items.push(None);
+        }
         Ok(self)
     }
 
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.tuple_lens.push((len, 0));
         self.serialize_seq(Some(len))
     }
 
@@ -284,13 +1056,28 @@ where
         name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        if name == GARNISH_PAIR_NAME {
+            if len != 2 {
+                return Err(GarnishSerializationError::from(
+                    "GarnishPair must have exactly 2 fields.",
+                ));
+            }
+            self.pair = Some(Vec::with_capacity(2));
+            return Ok(self);
+        }
+
         match self.options.struct_typing_behavior {
-            StructBehavior::IncludeTyping => {
-                self.struct_sym = Some(self.data.parse_add_symbol(name).or_else(wrap_err)?)
+            StructBehavior::IncludeTyping => self.start_struct_typing(name)?,
+            StructBehavior::IncludeTypingAsPair => {
+                self.struct_tags.push(None);
+                self.start_list(len + 1)?;
+                self.add_struct_typing_pair(name)?;
+                return Ok(self);
             }
-            StructBehavior::ExcludeTyping => (),
+            StructBehavior::ExcludeTyping => self.struct_tags.push(None),
         }
-        self.serialize_seq(Some(len))
+        self.start_list(len)?;
+        Ok(self)
     }
 
     fn serialize_tuple_variant(
@@ -300,12 +1087,16 @@ where
         variant: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        self.struct_sym = Some(self.serialize_unit_variant(name, variant_index, variant)?);
-        self.serialize_seq(Some(len))
+        let sym = self.serialize_unit_variant(name, variant_index, variant)?;
+        self.struct_tags.push(Some(StructTag::Symbol(sym)));
+        self.start_list(len)?;
+        Ok(self)
     }
 
     fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        self.serialize_seq(len)
+        self.start_associations();
+        self.start_list(len.unwrap_or(0))?;
+        Ok(self)
     }
 
     fn serialize_struct(
@@ -313,13 +1104,24 @@ where
         name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        match self.options.struct_typing_behavior {
-            StructBehavior::IncludeTyping => {
-                self.struct_sym = Some(self.data.parse_add_symbol(name).or_else(wrap_err)?)
-            }
-            StructBehavior::ExcludeTyping => (),
+        // std::ops::Range and RangeInclusive serialize as structs
+        // with these names and fields "start" and "end"
+        if self.options.range_behavior == RangeBehavior::GarnishRange
+            && (name == "Range" || name == "RangeInclusive")
+            && len == 2
+        {
+            self.range = Some(PendingRange {
+                name,
+                len,
+                inclusive: name == "RangeInclusive",
+                start: None,
+                end: None,
+            });
+            return Ok(self);
         }
-        self.serialize_seq(Some(len))
+
+        self.start_struct(name, len)?;
+        Ok(self)
     }
 
     fn serialize_struct_variant(
@@ -329,8 +1131,11 @@ where
         variant: &'static str,
         len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        self.struct_sym = Some(self.serialize_unit_variant(name, variant_index, variant)?);
-        self.serialize_seq(Some(len))
+        let sym = self.serialize_unit_variant(name, variant_index, variant)?;
+        self.struct_tags.push(Some(StructTag::Symbol(sym)));
+        self.start_associations();
+        self.start_list(len)?;
+        Ok(self)
     }
 }
 
@@ -351,12 +1156,11 @@ where
     where
         T: Serialize,
     {
-        let addr = value.serialize(&mut **self)?;
-        self.data.add_to_list(addr, false).or_else(wrap_err)
+        self.serialize_sequence_element(value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.data.end_list().or_else(wrap_err)
+        self.end_sequence()
     }
 }
 
@@ -377,10 +1181,33 @@ where
     where
         T: Serialize,
     {
-        let v = key.serialize(&mut **self)?;
-        // might not be a char list
-        // let data perform conversion
-        self.pending_key = Some(self.data.add_symbol_from(v).or_else(wrap_err)?);
+        self.char_addr = None;
+        if self.buffers_associations() {
+            self.key_symbols = Some(vec![]);
+        }
+        let v = key.serialize(&mut **self);
+        let key_symbols = self.key_symbols.take();
+        let v = v?;
+        if let Some(symbols) = key_symbols {
+            self.pending_key_name = Some(self.key_name(v, &symbols)?);
+        }
+
+        // char keys are kept as serialized so they can be read back as chars
+        let char_key = self.char_addr.take() == Some(v);
+        let symbol_key = match self.options.map_key_behavior {
+            MapKeyBehavior::AlwaysSymbol => !char_key,
+            MapKeyBehavior::PreserveType => false,
+            MapKeyBehavior::PreserveNumbers => {
+                !char_key
+                    && self.data.get_data_type(v).or_else(wrap_err)? != GarnishDataType::Number
+            }
+        };
+        self.pending_key = Some(match symbol_key {
+            // might not be a char list
+            // let data perform conversion
+            true => self.data.add_symbol_from(v).or_else(wrap_err)?,
+            false => v,
+        });
         Ok(())
     }
 
@@ -393,15 +1220,18 @@ where
                 "No key when serializing value for map.",
             )),
             Some(key) => {
+                let name = self.pending_key_name.take();
                 let val = value.serialize(&mut **self)?;
                 let pair = self.data.add_pair((key, val)).or_else(wrap_err)?;
-                self.data.add_to_list(pair, true).or_else(wrap_err)
+                self.add_association(name, pair)
             }
         }
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.data.end_list().or_else(wrap_err)
+        self.end_associations()?;
+        let addr = self.data.end_list().or_else(wrap_err);
+        self.completed(addr)
     }
 }
 
@@ -426,14 +1256,43 @@ where
     where
         T: Serialize,
     {
-        let k = self.data.parse_add_symbol(key).or_else(wrap_err)?;
-        let v = value.serialize(&mut **self)?;
-        let p = self.data.add_pair((k, v)).or_else(wrap_err)?;
-        self.data.add_to_list(p, true).or_else(wrap_err)
+        // take range so nested structs aren't affected
+        if let Some(mut range) = self.range.take() {
+            match key {
+                "start" if range.start.is_none() && range.end.is_none() => {
+                    range.start = Some(value.serialize(&mut **self)?);
+                }
+                "end" if range.start.is_some() && range.end.is_none() => {
+                    range.end = Some(self.serialize_range_end(range.inclusive, value)?);
+                }
+                _ => {
+                    self.start_range_as_struct(range)?;
+                    return self.serialize_struct_field(key, value);
+                }
+            }
+            self.range = Some(range);
+            return Ok(());
+        }
+
+        self.serialize_struct_field(key, value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.end_struct_like()
+        match self.range.take() {
+            Some(PendingRange {
+                start: Some(start),
+                end: Some(end),
+                ..
+            }) => {
+                let addr = self.data.add_range(start, end).or_else(wrap_err);
+                return self.completed(addr);
+            }
+            Some(range) => self.start_range_as_struct(range)?,
+            None => (),
+        }
+
+        self.end_associations()?;
+        self.end_struct_like(self.options.empty_struct_behavior)
     }
 }
 
@@ -458,14 +1317,12 @@ where
     where
         T: Serialize,
     {
-        let k = self.data.parse_add_symbol(key).or_else(wrap_err)?;
-        let v = value.serialize(&mut **self)?;
-        let p = self.data.add_pair((k, v)).or_else(wrap_err)?;
-        self.data.add_to_list(p, true).or_else(wrap_err)
+        self.serialize_struct_field(key, value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.end_struct_like()
+        self.end_associations()?;
+        self.end_struct_like(self.options.empty_struct_behavior)
     }
 }
 
@@ -486,12 +1343,33 @@ where
     where
         T: Serialize,
     {
-        let addr = value.serialize(&mut **self)?;
-        self.data.add_to_list(addr, false).or_else(wrap_err)
+        // check before serializing so data never receives more items than declared
+        if let Some((len, count)) = self.tuple_lens.last_mut() {
+            if *count >= *len {
+                return Err(GarnishSerializationError::from(
+                    format!("Tuple declared with length {} received more elements.", len).as_str(),
+                ));
+            }
+            *count += 1;
+        }
+
+        self.serialize_sequence_element(value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.data.end_list().or_else(wrap_err)
+        if let Some((len, count)) = self.tuple_lens.pop() {
+            if count != len {
+                return Err(GarnishSerializationError::from(
+                    format!(
+                        "Tuple declared with length {} received {} elements.",
+                        len, count
+                    )
+                    .as_str(),
+                ));
+            }
+        }
+
+        self.end_sequence()
     }
 }
 
@@ -512,12 +1390,36 @@ where
     where
         T: Serialize,
     {
+        // take pair so nested values aren't affected
+        if let Some(mut pair) = self.pair.take() {
+            let addr = value.serialize(&mut **self)?;
+            let addr = match pair.is_empty() {
+                // same as map keys, let data perform conversion
+                true => self.data.add_symbol_from(addr).or_else(wrap_err)?,
+                false => addr,
+            };
+            pair.push(addr);
+            self.pair = Some(pair);
+            return Ok(());
+        }
+
         let addr = value.serialize(&mut **self)?;
         self.data.add_to_list(addr, false).or_else(wrap_err)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.end_struct_like()
+        match self.pair.take() {
+            Some(pair) => match pair.as_slice() {
+                [left, right] => {
+                    let pair = self.data.add_pair((*left, *right)).or_else(wrap_err);
+                    self.completed(pair)
+                }
+                _ => Err(GarnishSerializationError::from(
+                    "GarnishPair must have exactly 2 fields.",
+                )),
+            },
+            None => self.end_struct_like(EmptyStructBehavior::EmptyList),
+        }
     }
 }
 
@@ -543,7 +1445,7 @@ where
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        self.end_struct_like()
+        self.end_struct_like(EmptyStructBehavior::EmptyList)
     }
 }
 
@@ -554,7 +1456,8 @@ mod tests {
     use garnish_lang_simple_data::{symbol_value, SimpleData, SimpleGarnishData, SimpleNumber};
 
     use crate::serializer::{
-        GarnishDataSerializer, OptionalBehavior, StructBehavior, VariantNameBehavior,
+        BytesBehavior, CharSerializationBehavior, GarnishDataSerializer, NumberFormatBehavior,
+        OptionalBehavior, StructBehavior, VariantNameBehavior,
     };
     use crate::GarnishSerializationOptions;
 
@@ -614,413 +1517,1756 @@ mod tests {
     }
 
     #[test]
-    fn serialize_i64() {
+    fn data_addr_none_before_serializing() {
+        let mut data = SimpleGarnishData::new();
+        let serializer = GarnishDataSerializer::new(&mut data);
+
+        assert_eq!(serializer.data_addr(), None);
+    }
+
+    #[test]
+    fn data_addr_after_serialize_i32() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_i64(125).unwrap();
+        let addr = serializer.serialize_i32(125).unwrap();
 
-        let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
+        assert_eq!(serializer.data_addr(), Some(addr));
     }
 
     #[test]
-    fn serialize_u8() {
+    fn data_addr_after_serialize_bool() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_u8(125).unwrap();
+        serializer.serialize_i32(125).unwrap();
+        let addr = serializer.serialize_bool(true).unwrap();
 
-        let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
+        assert_eq!(serializer.data_addr(), Some(addr));
     }
 
     #[test]
-    fn serialize_u16() {
+    fn data_addr_after_serialize_struct() {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping),
+        );
 
-        let addr = serializer.serialize_u16(125).unwrap();
+        let addr = Point { x: 1, y: 2 }.serialize(&mut serializer).unwrap();
 
-        let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
+        assert_eq!(serializer.data_addr(), Some(addr));
     }
 
     #[test]
-    fn serialize_u32() {
+    fn serialize_i64() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_u32(125).unwrap();
+        let addr = serializer.serialize_i64(125).unwrap();
 
         let num = data.get_data().get(addr).unwrap();
         assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
     }
 
     #[test]
-    fn serialize_u64() {
+    fn serialize_u8() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_u64(125).unwrap();
+        let addr = serializer.serialize_u8(125).unwrap();
 
         let num = data.get_data().get(addr).unwrap();
         assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
     }
 
     #[test]
-    fn serialize_f32() {
+    fn serialize_u16() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_f32(125.0).unwrap();
+        let addr = serializer.serialize_u16(125).unwrap();
 
         let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(125.0)));
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
     }
 
     #[test]
-    fn serialize_f64() {
+    fn serialize_u32() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_f64(125.0).unwrap();
+        let addr = serializer.serialize_u32(125).unwrap();
 
         let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(125.0)));
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
     }
 
     #[test]
-    fn serialize_char() {
+    fn serialize_u64() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_char('a').unwrap();
+        let addr = serializer.serialize_u64(125).unwrap();
 
         let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::Char('a'));
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(125)));
     }
 
     #[test]
-    fn serialize_str() {
+    fn serialize_compact_numbers_keeps_value() {
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().compact_numbers(true),
+        );
 
-        let addr = serializer.serialize_str("abcd").unwrap();
+        let small = serializer.serialize_u64(5).unwrap();
+        let negative = serializer.serialize_i64(-70_000).unwrap();
 
-        let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::CharList("abcd".to_string()));
+        assert_eq!(
+            data.get_data().get(small).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(5))
+        );
+        assert_eq!(
+            data.get_data().get(negative).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(-70_000))
+        );
     }
 
     #[test]
-    fn serialize_byte() {
+    fn serialize_u64_near_max_out_of_range_for_data() {
+        for v in [u64::MAX, u64::MAX - 1, i32::MAX as u64 + 1] {
+            let mut data = SimpleGarnishData::new();
+            let mut serializer = GarnishDataSerializer::new(&mut data);
+
+            let err = serializer.serialize_u64(v).unwrap_err();
+
+            assert_eq!(
+                err.message(),
+                Some(&format!(
+                    "Integer value {} is out of range for this data's numbers.",
+                    v
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_i64_in_range_for_data() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_bytes(&[1, 2, 3, 4]).unwrap();
+        let addr = serializer.serialize_i64(i32::MIN as i64).unwrap();
 
-        let num = data.get_data().get(addr).unwrap();
-        assert_eq!(num, &SimpleData::ByteList(vec![1, 2, 3, 4]));
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(i32::MIN))
+        );
     }
 
     #[test]
-    fn serialize_none_symbol() {
+    fn serialize_compact_numbers_out_of_range_for_data() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+            GarnishSerializationOptions::new().compact_numbers(true),
         );
 
-        let addr = serializer.serialize_none().unwrap();
-
-        assert_eq!(
-            data.get_data().get(addr).unwrap(),
-            &SimpleData::Symbol(symbol_value("none"))
-        );
+        assert!(serializer.serialize_u64(u64::MAX).is_err());
     }
 
     #[test]
-    fn serialize_some_as_value_when_unit_symbol() {
+    fn serialize_large_whole_float_not_collapsed_when_out_of_range() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+            GarnishSerializationOptions::new()
+                .number_format_behavior(NumberFormatBehavior::CollapseWholeFloats),
         );
 
-        let addr = serializer.serialize_some(&10).unwrap();
+        let addr = serializer.serialize_f64(1e12).unwrap();
 
         assert_eq!(
             data.get_data().get(addr).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(10))
+            &SimpleData::Number(SimpleNumber::Float(1e12))
         );
     }
 
     #[test]
-    fn serialize_none_as_unit() {
+    fn serialize_values_with_reset() {
+        use serde::Serialize;
+
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_none().unwrap();
-
-        assert_eq!(data.get_data().get(addr).unwrap(), &SimpleData::Unit);
-    }
+        let number = 10.serialize(&mut serializer).unwrap();
+        serializer.reset();
+        assert_eq!(serializer.data_addr(), None);
 
-    #[test]
-    fn serialize_some_as_value() {
-        let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let text = "text".serialize(&mut serializer).unwrap();
+        serializer.reset();
 
-        let addr = serializer.serialize_some(&10).unwrap();
+        let pair = (1, 2).serialize(&mut serializer).unwrap();
+        serializer.reset();
 
         assert_eq!(
-            data.get_data().get(addr).unwrap(),
+            data.get_data().get(number).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(10))
         );
+        assert_eq!(
+            data.get_data().get(text).unwrap(),
+            &SimpleData::CharList("text".to_string())
+        );
+        let list = data.get_data().get(pair).unwrap().as_list().unwrap().0;
+        assert_eq!(
+            data.get_data().get(list[1]).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(2))
+        );
     }
 
     #[test]
-    fn serialize_unit() {
+    fn serialize_f32() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer.serialize_unit().unwrap();
+        let addr = serializer.serialize_f32(125.0).unwrap();
 
-        assert_eq!(data.get_data().get(addr).unwrap(), &SimpleData::Unit);
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(125.0)));
     }
 
     #[test]
-    fn serialize_unit_struct_as_unit() {
+    fn serialize_integer_json_compat() {
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().json_compat(true),
+        );
 
-        let addr = serializer.serialize_unit_struct("PhantomData").unwrap();
+        let addr = serializer.serialize_i32(125).unwrap();
 
-        assert_eq!(data.get_data().get(addr).unwrap(), &SimpleData::Unit);
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(125.0)));
     }
 
     #[test]
-    fn serialize_unit_struct_as_list() {
+    fn serialize_whole_float_json_compat_not_collapsed() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
             GarnishSerializationOptions::new()
-                .struct_typing_behavior(StructBehavior::IncludeTyping),
+                .json_compat(true)
+                .number_format_behavior(NumberFormatBehavior::CollapseWholeFloats),
         );
 
-        let addr = serializer.serialize_unit_struct("PhantomData").unwrap();
-
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let addr = serializer.serialize_f64(2.0).unwrap();
 
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("PhantomData"))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Unit
-        );
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(2.0)));
     }
 
     #[test]
-    fn serialize_variant_full_name() {
+    fn serialize_whole_float_exact() {
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().number_format_behavior(NumberFormatBehavior::Exact),
+        );
 
-        let addr = serializer
-            .serialize_unit_variant("MyEnum", 100, "Value1")
-            .unwrap();
+        let addr = serializer.serialize_f64(2.0).unwrap();
 
-        assert_eq!(
-            data.get_data().get(addr).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyEnum::Value1"))
-        );
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(2.0)));
     }
 
     #[test]
-    fn serialize_variant_short_name() {
+    fn serialize_whole_float_collapsed() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+            GarnishSerializationOptions::new()
+                .number_format_behavior(NumberFormatBehavior::CollapseWholeFloats),
         );
 
-        let addr = serializer
-            .serialize_unit_variant("MyEnum", 100, "Value1")
-            .unwrap();
+        let addr = serializer.serialize_f64(2.0).unwrap();
 
-        assert_eq!(
-            data.get_data().get(addr).unwrap(),
-            &SimpleData::Symbol(symbol_value("Value1"))
-        );
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Integer(2)));
     }
 
     #[test]
-    fn serialize_variant_index() {
+    fn serialize_fractional_float_not_collapsed() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+            GarnishSerializationOptions::new()
+                .number_format_behavior(NumberFormatBehavior::CollapseWholeFloats),
         );
 
-        let addr = serializer
-            .serialize_unit_variant("MyEnum", 100, "Value1")
-            .unwrap();
+        let addr = serializer.serialize_f32(2.5).unwrap();
 
-        assert_eq!(
-            data.get_data().get(addr).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
-        );
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(2.5)));
     }
 
     #[test]
-    fn serialize_new_type_variant_full_name() {
+    fn serialize_f64() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let addr = serializer
-            .serialize_newtype_variant("MyEnum", 100, "Value1", &200)
-            .unwrap();
-
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let addr = serializer.serialize_f64(125.0).unwrap();
 
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyEnum::Value1"))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
-        );
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Number(SimpleNumber::Float(125.0)));
     }
 
     #[test]
-    fn serialize_new_type_variant_short_name() {
-        let mut data = SimpleGarnishData::new();
+    fn serialize_f64_records_lossy_floats() {
+        use crate::serializer::LossyFloat;
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
+
+        let mut data = UncachedGarnishData::new_with_f32_floats();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+            GarnishSerializationOptions::new().detect_lossy_floats(true),
         );
 
-        let addr = serializer
-            .serialize_newtype_variant("MyEnum", 100, "Value1", &200)
-            .unwrap();
+        vec![0.5, 0.1, 5e-324].serialize(&mut serializer).unwrap();
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let lossy: Vec<(f64, f64)> = serializer
+            .lossy_floats()
+            .iter()
+            .map(|LossyFloat { value, stored, .. }| (*value, *stored))
+            .collect();
+        assert_eq!(lossy, vec![(0.1, 0.1f32 as f64), (5e-324, 0.0)]);
+    }
 
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("Value1"))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
-        );
+    #[test]
+    fn serialize_f64_lossy_floats_not_recorded_by_default() {
+        use crate::test_data::UncachedGarnishData;
+
+        let mut data = UncachedGarnishData::new_with_f32_floats();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        serializer.serialize_f64(0.1).unwrap();
+
+        assert!(serializer.lossy_floats().is_empty());
     }
 
     #[test]
-    fn serialize_new_type_variant_index() {
+    fn serialize_f64_exact_floats_not_recorded() {
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+            GarnishSerializationOptions::new().detect_lossy_floats(true),
         );
 
-        let addr = serializer
-            .serialize_newtype_variant("MyEnum", 100, "Value1", &200)
-            .unwrap();
+        serializer.serialize_f64(0.1).unwrap();
+        serializer.serialize_f64(5e-324).unwrap();
+        serializer.serialize_f64(f64::NAN).unwrap();
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        assert!(serializer.lossy_floats().is_empty());
+    }
 
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+    #[test]
+    fn serialize_char() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_char('a').unwrap();
+
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::Char('a'));
+    }
+
+    #[test]
+    fn serialize_char_as_char_list() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .char_serialization_behavior(CharSerializationBehavior::CharList),
+        );
+
+        let addr = serializer.serialize_char('x').unwrap();
+
+        let value = data.get_data().get(addr).unwrap();
+        assert_eq!(value, &SimpleData::CharList("x".to_string()));
+    }
+
+    #[test]
+    fn serialize_bytes_as_char_list() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().bytes_behavior(BytesBehavior::CharListUtf8),
+        );
+
+        let addr = serializer.serialize_bytes("text ✓".as_bytes()).unwrap();
+
+        let value = data.get_data().get(addr).unwrap();
+        assert_eq!(value, &SimpleData::CharList("text ✓".to_string()));
+    }
+
+    #[test]
+    fn serialize_invalid_utf8_bytes_as_char_list() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().bytes_behavior(BytesBehavior::CharListUtf8),
+        );
+
+        let err = serializer.serialize_bytes(&[b'a', 0xff, b'b']).unwrap_err();
+
+        assert!(err
+            .message()
+            .unwrap()
+            .starts_with("Bytes are not valid UTF-8:"));
+    }
+
+    #[test]
+    fn serialize_str() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_str("abcd").unwrap();
+
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::CharList("abcd".to_string()));
+    }
+
+    #[test]
+    fn serialize_byte() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_bytes(&[1, 2, 3, 4]).unwrap();
+
+        let num = data.get_data().get(addr).unwrap();
+        assert_eq!(num, &SimpleData::ByteList(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn serialize_none_symbol() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+        );
+
+        let addr = serializer.serialize_none().unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Symbol(symbol_value("none"))
+        );
+    }
+
+    #[test]
+    fn serialize_none_custom_symbol() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .optional_behavior(OptionalBehavior::UnitSymbol)
+                .none_symbol("nil"),
+        );
+
+        let addr = serializer.serialize_none().unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Symbol(symbol_value("nil"))
+        );
+    }
+
+    #[test]
+    fn serialize_some_as_value_when_unit_symbol() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().optional_behavior(OptionalBehavior::UnitSymbol),
+        );
+
+        let addr = serializer.serialize_some(&10).unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(10))
+        );
+    }
+
+    #[test]
+    fn serialize_none_as_unit() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_none().unwrap();
+
+        assert_eq!(data.get_data().get(addr).unwrap(), &SimpleData::Unit);
+    }
+
+    #[test]
+    fn serialize_some_as_value() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_some(&10).unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(10))
+        );
+    }
+
+    #[test]
+    fn serialize_ref_cell() {
+        use serde::Serialize;
+        use std::cell::RefCell;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = RefCell::new(10).serialize(&mut serializer).unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(10))
+        );
+    }
+
+    #[test]
+    fn serialize_ref_cell_mutably_borrowed() {
+        use serde::Serialize;
+        use std::cell::RefCell;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let cell = RefCell::new(10);
+        let _borrow = cell.borrow_mut();
+
+        let err = cell.serialize(&mut serializer).unwrap_err();
+
+        assert_eq!(err.message(), Some(&"already mutably borrowed".to_string()));
+    }
+
+    #[test]
+    fn serialize_mutex() {
+        use serde::Serialize;
+        use std::sync::Mutex;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = Mutex::new("abcd".to_string())
+            .serialize(&mut serializer)
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::CharList("abcd".to_string())
+        );
+    }
+
+    #[test]
+    fn serialize_poisoned_mutex() {
+        use serde::Serialize;
+        use std::sync::{Arc, Mutex};
+
+        let mutex = Arc::new(Mutex::new("abcd".to_string()));
+        let poisoner = Arc::clone(&mutex);
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poisoning mutex");
+        })
+        .join();
+
+        assert!(mutex.is_poisoned());
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let err = mutex.serialize(&mut serializer).unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            Some(&"lock poison error while serializing".to_string())
+        );
+        assert!(err.error().is_none());
+    }
+
+    #[test]
+    fn serialize_unit() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_unit().unwrap();
+
+        assert_eq!(data.get_data().get(addr).unwrap(), &SimpleData::Unit);
+    }
+
+    #[test]
+    fn serialize_unit_struct_as_unit() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer.serialize_unit_struct("PhantomData").unwrap();
+
+        assert_eq!(data.get_data().get(addr).unwrap(), &SimpleData::Unit);
+    }
+
+    #[test]
+    fn serialize_unit_struct_as_list() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping),
+        );
+
+        let addr = serializer.serialize_unit_struct("PhantomData").unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("PhantomData"))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Unit
+        );
+    }
+
+    #[test]
+    fn serialize_unit_struct_within_declared_list_len() {
+        use crate::test_data::UncachedGarnishData;
+
+        let mut data = UncachedGarnishData::new_validating_list_len();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping),
+        );
+
+        let addr = serializer.serialize_unit_struct("PhantomData").unwrap();
+
+        let list = data
+            .inner()
+            .get_data()
+            .get(addr)
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn serialize_newtype_variant_within_declared_list_len() {
+        use crate::test_data::UncachedGarnishData;
+
+        let mut data = UncachedGarnishData::new_validating_list_len();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer
+            .serialize_newtype_variant("MyEnum", 0, "Value1", &10)
+            .unwrap();
+
+        let list = data
+            .inner()
+            .get_data()
+            .get(addr)
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn serialize_variant_full_name() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer
+            .serialize_unit_variant("MyEnum", 100, "Value1")
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyEnum::Value1"))
+        );
+    }
+
+    #[test]
+    fn serialize_variant_short_name() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+        );
+
+        let addr = serializer
+            .serialize_unit_variant("MyEnum", 100, "Value1")
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Symbol(symbol_value("Value1"))
+        );
+    }
+
+    #[test]
+    fn serialize_variant_index() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+        );
+
+        let addr = serializer
+            .serialize_unit_variant("MyEnum", 100, "Value1")
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+    }
+
+    #[test]
+    fn serialize_variant_short_name_string() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .variant_name_behavior(VariantNameBehavior::ShortString),
+        );
+
+        let addr = serializer
+            .serialize_unit_variant("MyEnum", 100, "Value1")
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::CharList("Value1".to_string())
+        );
+    }
+
+    #[test]
+    fn serialize_variant_full_name_string() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .variant_name_behavior(VariantNameBehavior::FullString),
+        );
+
+        let addr = serializer
+            .serialize_unit_variant("MyEnum", 100, "Value1")
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(addr).unwrap(),
+            &SimpleData::CharList("MyEnum::Value1".to_string())
+        );
+    }
+
+    #[test]
+    fn serialize_new_type_variant_full_name() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = serializer
+            .serialize_newtype_variant("MyEnum", 100, "Value1", &200)
+            .unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyEnum::Value1"))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+    }
+
+    #[test]
+    fn serialize_new_type_variant_short_name() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+        );
+
+        let addr = serializer
+            .serialize_newtype_variant("MyEnum", 100, "Value1", &200)
+            .unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("Value1"))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+    }
+
+    #[test]
+    fn serialize_new_type_variant_index() {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+        );
+
+        let addr = serializer
+            .serialize_newtype_variant("MyEnum", 100, "Value1", &200)
+            .unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+    }
+}
+
+#[cfg(test)]
+mod compound {
+    use serde::Serializer;
+
+    use crate::GarnishSerializationOptions;
+    use garnish_lang_simple_data::{symbol_value, SimpleData, SimpleGarnishData, SimpleNumber};
+
+    use crate::serializer::{
+        GarnishDataSerializer, MapKeyOrdering, RangeBehavior, SequenceBehavior, StructBehavior,
+        TypeSymbolTiming, VariantNameBehavior,
+    };
+
+    #[test]
+    fn serialize_sequence() {
+        use serde::ser::SerializeSeq;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut serializer = serializer.serialize_seq(None).unwrap();
+
+        serializer.serialize_element(&100).unwrap();
+        serializer.serialize_element(&200).unwrap();
+        serializer.serialize_element(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    fn serialize_with_sequence_behavior<T: serde::Serialize>(
+        value: &T,
+        behavior: SequenceBehavior,
+    ) -> (SimpleGarnishData, usize) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().sequence_behavior(behavior),
+        );
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        (data, addr)
+    }
+
+    #[test]
+    fn serialize_vec_with_each_sequence_behavior() {
+        let (data, addr) = serialize_with_sequence_behavior(&vec![1, 2, 3], SequenceBehavior::List);
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let items: Vec<&SimpleData> = list
+            .iter()
+            .map(|i| data.get_data().get(*i).unwrap())
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                &SimpleData::Number(SimpleNumber::Integer(1)),
+                &SimpleData::Number(SimpleNumber::Integer(2)),
+                &SimpleData::Number(SimpleNumber::Integer(3)),
+            ]
+        );
+
+        let (data, addr) =
+            serialize_with_sequence_behavior(&vec![1, 2, 3], SequenceBehavior::Concatenation);
+        let (left, right) = data
+            .get_data()
+            .get(addr)
+            .unwrap()
+            .as_concatenation()
+            .unwrap();
+        let (first, second) = data
+            .get_data()
+            .get(left)
+            .unwrap()
+            .as_concatenation()
+            .unwrap();
+        assert_eq!(
+            [first, second, right].map(|i| data.get_data().get(i).unwrap()),
+            [
+                &SimpleData::Number(SimpleNumber::Integer(1)),
+                &SimpleData::Number(SimpleNumber::Integer(2)),
+                &SimpleData::Number(SimpleNumber::Integer(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn serialize_short_sequences_as_lists_with_concatenation_behavior() {
+        let (data, addr) =
+            serialize_with_sequence_behavior(&Vec::<i32>::new(), SequenceBehavior::Concatenation);
+        assert_eq!(
+            data.get_data()
+                .get(addr)
+                .unwrap()
+                .as_list()
+                .unwrap()
+                .0
+                .len(),
+            0
+        );
+
+        let (data, addr) = serialize_with_sequence_behavior(&(5,), SequenceBehavior::Concatenation);
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        assert_eq!(
+            data.get_data().get(list[0]).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(5))
+        );
+    }
+
+    #[test]
+    fn serialize_tuple() {
+        use serde::ser::SerializeTuple;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut serializer = serializer.serialize_tuple(3).unwrap();
+
+        serializer.serialize_element(&100).unwrap();
+        serializer.serialize_element(&200).unwrap();
+        serializer.serialize_element(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    #[test]
+    fn serialize_struct_without_name() {
+        use serde::ser::SerializeStruct;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut serializer = serializer.serialize_struct("MyTuple", 3).unwrap();
+
+        serializer.serialize_field("one", &100).unwrap();
+        serializer.serialize_field("two", &200).unwrap();
+        serializer.serialize_field("three", &300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        assert_eq!(list.len(), 3);
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(0).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("one"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("two"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(2).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("three"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    #[test]
+    fn serialize_struct_with_name() {
+        use serde::ser::SerializeStruct;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping),
+        );
+
+        let mut serializer = serializer.serialize_tuple_struct("MyStruct", 3).unwrap();
+
+        serializer.serialize_field("one", &100).unwrap();
+        serializer.serialize_field("two", &200).unwrap();
+        serializer.serialize_field("three", &300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyStruct"))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(0).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("one"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("two"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(2).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("three"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    fn serialize_struct_with_timing(timing: TypeSymbolTiming) -> (usize, usize) {
+        use serde::ser::SerializeStruct;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping)
+                .type_symbol_timing(timing),
+        );
+
+        let mut serializer = serializer.serialize_struct("MyStruct", 2).unwrap();
+
+        serializer.serialize_field("one", &100).unwrap();
+        serializer.serialize_field("two", &200).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let type_sym = list[0];
+
+        assert_eq!(
+            data.get_data().get(type_sym).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyStruct"))
+        );
+
+        let fields = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+        let (field_sym, _) = data.get_data().get(fields[0]).unwrap().as_pair().unwrap();
+
+        (type_sym, field_sym)
+    }
+
+    fn serialize_nested_struct_with_timing(timing: TypeSymbolTiming) {
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Inner {
+            a: i32,
+        }
+
+        #[derive(Serialize)]
+        struct Outer {
+            inner: Inner,
+            b: i32,
+        }
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping)
+                .type_symbol_timing(timing),
+        );
+
+        let addr = Outer {
+            inner: Inner { a: 1 },
+            b: 2,
+        }
+        .serialize(&mut serializer)
+        .unwrap();
+
+        let data = data.inner().get_data();
+        // each struct is a list of its type symbol and its fields
+        let tagged_fields = |addr: usize, name: &str| {
+            let list = data.get(addr).unwrap().as_list().unwrap().0;
+            assert_eq!(list.len(), 2);
+            assert_eq!(
+                data.get(list[0]).unwrap(),
+                &SimpleData::Symbol(symbol_value(name))
+            );
+            data.get(list[1]).unwrap().as_list().unwrap().0
+        };
+
+        let outer_fields = tagged_fields(addr, "Outer");
+        assert_eq!(outer_fields.len(), 2);
+        let (_, inner) = data.get(outer_fields[0]).unwrap().as_pair().unwrap();
+        assert_eq!(tagged_fields(inner, "Inner").len(), 1);
+    }
+
+    #[test]
+    fn serialize_nested_struct_type_symbol_before_fields() {
+        serialize_nested_struct_with_timing(TypeSymbolTiming::Before);
+    }
+
+    #[test]
+    fn serialize_nested_struct_type_symbol_after_fields() {
+        serialize_nested_struct_with_timing(TypeSymbolTiming::After);
+    }
+
+    #[test]
+    fn serialize_struct_type_symbol_before_fields() {
+        let (type_sym, field_sym) = serialize_struct_with_timing(TypeSymbolTiming::Before);
+
+        assert!(type_sym < field_sym);
+    }
+
+    #[test]
+    fn serialize_struct_type_symbol_after_fields() {
+        let (type_sym, field_sym) = serialize_struct_with_timing(TypeSymbolTiming::After);
+
+        assert!(type_sym > field_sym);
+    }
+
+    fn serialize_struct_with_unit_field(skip_unit_fields: bool) -> usize {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Marked {
+            value: i32,
+            marker: (),
+        }
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().skip_unit_fields(skip_unit_fields),
+        );
+
+        let addr = Marked {
+            value: 10,
+            marker: (),
+        }
+        .serialize(&mut serializer)
+        .unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let keys: Vec<SimpleData> = list
+            .iter()
+            .map(|item| {
+                let (left, _) = data.get_data().get(*item).unwrap().as_pair().unwrap();
+                data.get_data().get(left).unwrap().clone()
+            })
+            .collect();
+
+        assert_eq!(keys[0], SimpleData::Symbol(symbol_value("value")));
+        assert_eq!(
+            keys.contains(&SimpleData::Symbol(symbol_value("marker"))),
+            !skip_unit_fields
+        );
+
+        keys.len()
+    }
+
+    #[test]
+    fn serialize_struct_skip_unit_fields() {
+        assert_eq!(serialize_struct_with_unit_field(true), 1);
+    }
+
+    #[test]
+    fn serialize_struct_keeps_unit_fields_by_default() {
+        assert_eq!(serialize_struct_with_unit_field(false), 2);
+    }
+
+    #[test]
+    fn serialize_struct_skip_unit_fields_adds_no_unit() {
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Marked {
+            value: i32,
+            marker: (),
+            missing: Option<i32>,
+        }
+
+        let count_units = |data: &UncachedGarnishData| {
+            let values = data.inner().get_data();
+            (0..values.len())
+                .filter(|i| values.get(*i) == Some(&SimpleData::Unit))
+                .count()
+        };
+
+        let mut data = UncachedGarnishData::new();
+        let units = count_units(&data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().skip_unit_fields(true),
+        );
+
+        Marked {
+            value: 10,
+            marker: (),
+            missing: None,
+        }
+        .serialize(&mut serializer)
+        .unwrap();
+
+        assert_eq!(count_units(&data), units);
+    }
+
+    fn serialize_range<T: serde::Serialize>(value: T) -> (SimpleData, SimpleData) {
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().range_behavior(RangeBehavior::GarnishRange),
+        );
+
+        let addr = value.serialize(&mut serializer).unwrap();
+
+        let (start, end) = data.get_data().get(addr).unwrap().as_range().unwrap();
+        (
+            data.get_data().get(start).unwrap().clone(),
+            data.get_data().get(end).unwrap().clone(),
+        )
+    }
+
+    #[test]
+    fn serialize_range_as_garnish_range() {
+        assert_eq!(
+            serialize_range(0..10),
+            (
+                SimpleData::Number(SimpleNumber::Integer(0)),
+                SimpleData::Number(SimpleNumber::Integer(9))
+            )
+        );
+    }
+
+    #[test]
+    fn serialize_range_inclusive_as_garnish_range() {
+        assert_eq!(
+            serialize_range(0..=10),
+            (
+                SimpleData::Number(SimpleNumber::Integer(0)),
+                SimpleData::Number(SimpleNumber::Integer(10))
+            )
+        );
+    }
+
+    #[test]
+    fn serialize_float_range_fails() {
+        use serde::Serialize;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().range_behavior(RangeBehavior::GarnishRange),
+        );
+
+        assert!((0.0..1.5).serialize(&mut serializer).is_err());
+    }
+
+    #[test]
+    fn serialize_range_with_minimum_exclusive_end_fails() {
+        use crate::test_data::WideGarnishData;
+        use serde::Serialize;
+
+        let mut data = WideGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().range_behavior(RangeBehavior::GarnishRange),
+        );
+
+        let err = (i64::MIN..i64::MIN).serialize(&mut serializer).unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            Some(&format!(
+                "Exclusive Range end {} has no previous integer to convert to a Garnish Range.",
+                i64::MIN
+            ))
+        );
+    }
+
+    #[test]
+    fn serialize_exclusive_range_adds_only_previous_integer() {
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().range_behavior(RangeBehavior::GarnishRange),
+        );
+
+        let addr = (0..10).serialize(&mut serializer).unwrap();
+
+        let values = data.inner().get_data();
+        let (_, end) = values.get(addr).unwrap().as_range().unwrap();
+        assert_eq!(
+            values.get(end).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(9))
+        );
+        assert!(!(0..values.len())
+            .any(|i| values.get(i) == Some(&SimpleData::Number(SimpleNumber::Integer(10)))));
+    }
+
+    #[test]
+    fn serialize_struct_named_range_with_other_fields_as_struct() {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Range {
+            start: i32,
+            step: i32,
+        }
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().range_behavior(RangeBehavior::GarnishRange),
+        );
+
+        let addr = Range { start: 5, step: 2 }
+            .serialize(&mut serializer)
+            .unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let fields: Vec<(SimpleData, SimpleData)> = list
+            .iter()
+            .map(|item| {
+                let (left, right) = data.get_data().get(*item).unwrap().as_pair().unwrap();
+                (
+                    data.get_data().get(left).unwrap().clone(),
+                    data.get_data().get(right).unwrap().clone(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                (
+                    SimpleData::Symbol(symbol_value("start")),
+                    SimpleData::Number(SimpleNumber::Integer(5))
+                ),
+                (
+                    SimpleData::Symbol(symbol_value("step")),
+                    SimpleData::Number(SimpleNumber::Integer(2))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn serialize_range_as_struct_by_default() {
+        use serde::Serialize;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = (0..10).serialize(&mut serializer).unwrap();
+
+        assert!(data.get_data().get(addr).unwrap().as_list().is_ok());
+    }
+
+    #[test]
+    fn serialize_tuple_struct_without_name() {
+        use serde::ser::SerializeTupleStruct;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut serializer = serializer.serialize_tuple_struct("MyTuple", 3).unwrap();
+
+        serializer.serialize_field(&100).unwrap();
+        serializer.serialize_field(&200).unwrap();
+        serializer.serialize_field(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(100))
         );
         assert_eq!(
             data.get_data().get(*list.get(1).unwrap()).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(200))
         );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
     }
-}
 
-#[cfg(test)]
-mod compound {
-    use serde::Serializer;
+    #[test]
+    fn serialize_tuple_struct_with_name() {
+        use serde::ser::SerializeTupleStruct;
 
-    use crate::GarnishSerializationOptions;
-    use garnish_lang_simple_data::{symbol_value, SimpleData, SimpleGarnishData, SimpleNumber};
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTyping),
+        );
+
+        let mut serializer = serializer.serialize_tuple_struct("MyTuple", 3).unwrap();
+
+        serializer.serialize_field(&100).unwrap();
+        serializer.serialize_field(&200).unwrap();
+        serializer.serialize_field(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyTuple"))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
 
-    use crate::serializer::{GarnishDataSerializer, StructBehavior, VariantNameBehavior};
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
 
     #[test]
-    fn serialize_sequence() {
-        use serde::ser::SerializeSeq;
+    fn serialize_struct_variant_with_full_name() {
+        use serde::ser::SerializeStructVariant;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let mut serializer = serializer.serialize_seq(None).unwrap();
+        let mut serializer = serializer
+            .serialize_struct_variant("MyEnum", 100, "MyStruct", 3)
+            .unwrap();
 
-        serializer.serialize_element(&100).unwrap();
-        serializer.serialize_element(&200).unwrap();
-        serializer.serialize_element(&300).unwrap();
+        serializer.serialize_field("one", &100).unwrap();
+        serializer.serialize_field("two", &200).unwrap();
+        serializer.serialize_field("three", &300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyEnum::MyStruct"))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(0).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("one"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("two"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(2).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("three"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    #[test]
+    fn serialize_struct_variant_with_short_name() {
+        use serde::ser::SerializeStructVariant;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+        );
+
+        let mut serializer = serializer
+            .serialize_struct_variant("MyEnum", 100, "MyStruct", 3)
+            .unwrap();
+
+        serializer.serialize_field("one", &100).unwrap();
+        serializer.serialize_field("two", &200).unwrap();
+        serializer.serialize_field("three", &300).unwrap();
 
         let addr = serializer.end().unwrap();
 
         let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
 
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyStruct"))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(0).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("one"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
+
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("two"))
         );
         assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            data.get_data().get(right).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(200))
         );
-        assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
-        );
-    }
-
-    #[test]
-    fn serialize_tuple() {
-        use serde::ser::SerializeTuple;
 
-        let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
-
-        let mut serializer = serializer.serialize_tuple(3).unwrap();
-
-        serializer.serialize_element(&100).unwrap();
-        serializer.serialize_element(&200).unwrap();
-        serializer.serialize_element(&300).unwrap();
-
-        let addr = serializer.end().unwrap();
-
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let (left, right) = data
+            .get_data()
+            .get(*list.get(2).unwrap())
+            .unwrap()
+            .as_pair()
+            .unwrap();
 
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("three"))
         );
         assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            data.get_data().get(right).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(300))
         );
     }
 
     #[test]
-    fn serialize_struct_without_name() {
-        use serde::ser::SerializeStruct;
+    fn serialize_struct_variant_with_index_name() {
+        use serde::ser::SerializeStructVariant;
 
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+        );
 
-        let mut serializer = serializer.serialize_struct("MyTuple", 3).unwrap();
+        let mut serializer = serializer
+            .serialize_struct_variant("MyEnum", 100, "MyStruct", 3)
+            .unwrap();
 
         serializer.serialize_field("one", &100).unwrap();
         serializer.serialize_field("two", &200).unwrap();
@@ -1029,7 +3275,19 @@ mod compound {
         let addr = serializer.end().unwrap();
 
         let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
-        assert_eq!(list.len(), 3);
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
 
         let (left, right) = data
             .get_data()
@@ -1081,21 +3339,68 @@ mod compound {
     }
 
     #[test]
-    fn serialize_struct_with_name() {
-        use serde::ser::SerializeStruct;
+    fn serialize_tuple_variant_with_full_name() {
+        use serde::ser::SerializeTupleVariant;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut serializer = serializer
+            .serialize_tuple_variant("MyEnum", 100, "Type1", 3)
+            .unwrap();
+
+        serializer.serialize_field(&100).unwrap();
+        serializer.serialize_field(&200).unwrap();
+        serializer.serialize_field(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Symbol(symbol_value("MyEnum::Type1"))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    #[test]
+    fn serialize_tuple_variant_with_short_name() {
+        use serde::ser::SerializeTupleVariant;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new()
-                .struct_typing_behavior(StructBehavior::IncludeTyping),
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
         );
 
-        let mut serializer = serializer.serialize_tuple_struct("MyStruct", 3).unwrap();
+        let mut serializer = serializer
+            .serialize_tuple_variant("MyEnum", 100, "Type1", 3)
+            .unwrap();
 
-        serializer.serialize_field("one", &100).unwrap();
-        serializer.serialize_field("two", &200).unwrap();
-        serializer.serialize_field("three", &300).unwrap();
+        serializer.serialize_field(&100).unwrap();
+        serializer.serialize_field(&200).unwrap();
+        serializer.serialize_field(&300).unwrap();
 
         let addr = serializer.end().unwrap();
 
@@ -1103,7 +3408,56 @@ mod compound {
 
         assert_eq!(
             data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyStruct"))
+            &SimpleData::Symbol(symbol_value("Type1"))
+        );
+
+        let list = data
+            .get_data()
+            .get(*list.get(1).unwrap())
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    #[test]
+    fn serialize_tuple_variant_with_index_name() {
+        use serde::ser::SerializeTupleVariant;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+        );
+
+        let mut serializer = serializer
+            .serialize_tuple_variant("MyEnum", 100, "Type1", 3)
+            .unwrap();
+
+        serializer.serialize_field(&100).unwrap();
+        serializer.serialize_field(&200).unwrap();
+        serializer.serialize_field(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
         );
 
         let list = data
@@ -1114,6 +3468,40 @@ mod compound {
             .unwrap()
             .0;
 
+        assert_eq!(
+            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
+        );
+        assert_eq!(
+            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(300))
+        );
+    }
+
+    #[test]
+    fn serialize_map() {
+        use serde::ser::SerializeMap;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut serializer = serializer.serialize_map(None).unwrap();
+
+        serializer.serialize_key("one").unwrap();
+        serializer.serialize_value(&100).unwrap();
+        serializer.serialize_key("two").unwrap();
+        serializer.serialize_value(&200).unwrap();
+        serializer.serialize_key("three").unwrap();
+        serializer.serialize_value(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+
         let (left, right) = data
             .get_data()
             .get(*list.get(0).unwrap())
@@ -1164,544 +3552,761 @@ mod compound {
     }
 
     #[test]
-    fn serialize_tuple_struct_without_name() {
-        use serde::ser::SerializeTupleStruct;
+    fn serialize_garnish_pair() {
+        use crate::GarnishPair;
+        use serde::Serialize;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = GarnishPair("a", 1).serialize(&mut serializer).unwrap();
+
+        let (left, right) = data.get_data().get(addr).unwrap().as_pair().unwrap();
+        assert_eq!(
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("a"))
+        );
+        assert_eq!(
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(1))
+        );
+    }
+
+    #[test]
+    fn serialize_raw_list_with_concatenation_behavior() {
+        use garnish_lang_traits::GarnishData;
+
+        let mut data = SimpleGarnishData::new();
+        let addrs = [100, 200, 300].map(|n| data.add_number(SimpleNumber::Integer(n)).unwrap());
+
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().sequence_behavior(SequenceBehavior::Concatenation),
+        );
+        let addr = serializer.serialize_raw_list(&addrs).unwrap();
+
+        // same shape as a serialized sequence
+        let (left, right) = data
+            .get_data()
+            .get(addr)
+            .unwrap()
+            .as_concatenation()
+            .unwrap();
+        let (first, second) = data
+            .get_data()
+            .get(left)
+            .unwrap()
+            .as_concatenation()
+            .unwrap();
+        assert_eq!([first, second, right], addrs);
+    }
+
+    #[test]
+    fn serialize_raw_list() {
+        use garnish_lang_traits::GarnishData;
+
+        let mut data = SimpleGarnishData::new();
+        let addrs = [100, 200, 300].map(|n| data.add_number(SimpleNumber::Integer(n)).unwrap());
+        let len = data.get_data_len();
+
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let addr = serializer.serialize_raw_list(&addrs).unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        assert_eq!(list, &addrs);
+        // only the list was added
+        assert_eq!(data.get_data_len(), len + 1);
+    }
+
+    #[test]
+    fn serialize_sequence_of_u128() {
+        use serde::Serialize;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let mut serializer = serializer.serialize_tuple_struct("MyTuple", 3).unwrap();
-
-        serializer.serialize_field(&100).unwrap();
-        serializer.serialize_field(&200).unwrap();
-        serializer.serialize_field(&300).unwrap();
-
-        let addr = serializer.end().unwrap();
+        let addr = vec![100u128, 200u128, 300u128]
+            .serialize(&mut serializer)
+            .unwrap();
 
         let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
 
         assert_eq!(list.len(), 3);
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
+            data.get_data().get(list[0]).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(100))
         );
         assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
+            data.get_data().get(list[1]).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(200))
         );
         assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
+            data.get_data().get(list[2]).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(300))
         );
     }
 
     #[test]
-    fn serialize_tuple_struct_with_name() {
-        use serde::ser::SerializeTupleStruct;
+    fn serialize_sequence_of_u128_out_of_range() {
+        use serde::Serialize;
 
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new_with_options(
-            &mut data,
-            GarnishSerializationOptions::new()
-                .struct_typing_behavior(StructBehavior::IncludeTyping),
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let err = vec![100u128, u128::MAX]
+            .serialize(&mut serializer)
+            .unwrap_err();
+
+        assert_eq!(
+            err.message(),
+            Some(&format!(
+                "u128 value {} is out of range for Garnish numbers.",
+                u128::MAX
+            ))
         );
+    }
 
-        let mut serializer = serializer.serialize_tuple_struct("MyTuple", 3).unwrap();
+    #[test]
+    fn serialize_i128_near_max_out_of_range() {
+        for v in [i128::MAX, i128::MAX - 1, u64::MAX as i128 + 1] {
+            let mut data = SimpleGarnishData::new();
+            let mut serializer = GarnishDataSerializer::new(&mut data);
+
+            let err = serializer.serialize_i128(v).unwrap_err();
+
+            assert_eq!(
+                err.message(),
+                Some(&format!(
+                    "i128 value {} is out of range for Garnish numbers.",
+                    v
+                ))
+            );
+        }
+    }
 
-        serializer.serialize_field(&100).unwrap();
-        serializer.serialize_field(&200).unwrap();
-        serializer.serialize_field(&300).unwrap();
+    #[test]
+    fn serialize_i128_in_u64_range() {
+        use crate::test_data::{WideGarnishData, WideNumber};
+        use garnish_lang_traits::GarnishData;
 
-        let addr = serializer.end().unwrap();
+        let mut data = WideGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let addr = serializer.serialize_i128(u64::MAX as i128).unwrap();
 
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyTuple"))
+            data.get_number(addr).unwrap(),
+            WideNumber::Integer(u64::MAX as i128)
         );
+    }
 
-        let list = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_list()
-            .unwrap()
-            .0;
+    #[test]
+    fn serialize_map_of_i128() {
+        use serde::Serialize;
+        use std::collections::HashMap;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut map = HashMap::new();
+        map.insert("one".to_string(), -100i128);
+
+        let addr = map.serialize(&mut serializer).unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let (left, right) = data.get_data().get(list[0]).unwrap().as_pair().unwrap();
 
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("one"))
         );
         assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            data.get_data().get(right).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(-100))
         );
     }
 
     #[test]
-    fn serialize_struct_variant_with_full_name() {
-        use serde::ser::SerializeStructVariant;
+    fn serialize_map_of_i128_out_of_range() {
+        use serde::Serialize;
+        use std::collections::HashMap;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let mut serializer = serializer
-            .serialize_struct_variant("MyEnum", 100, "MyStruct", 3)
-            .unwrap();
-
-        serializer.serialize_field("one", &100).unwrap();
-        serializer.serialize_field("two", &200).unwrap();
-        serializer.serialize_field("three", &300).unwrap();
-
-        let addr = serializer.end().unwrap();
+        let mut map = HashMap::new();
+        map.insert("one".to_string(), i128::MIN);
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let err = map.serialize(&mut serializer).unwrap_err();
 
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyEnum::MyStruct"))
+            err.message(),
+            Some(&format!(
+                "i128 value {} is out of range for Garnish numbers.",
+                i128::MIN
+            ))
         );
+    }
 
-        let list = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_list()
-            .unwrap()
-            .0;
+    #[test]
+    fn serialize_map_with_i128_key() {
+        use serde::Serialize;
+        use std::collections::HashMap;
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(0).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let mut map = HashMap::new();
+        map.insert(5i128, 100);
+
+        let addr = map.serialize(&mut serializer).unwrap();
+
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let (left, right) = data.get_data().get(list[0]).unwrap().as_pair().unwrap();
 
         assert_eq!(
             data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("one"))
+            &SimpleData::Symbol(symbol_value("5"))
         );
         assert_eq!(
             data.get_data().get(right).unwrap(),
             &SimpleData::Number(SimpleNumber::Integer(100))
         );
+    }
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+    #[test]
+    fn serialize_sequence_deduplicated() {
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
 
-        assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("two"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().deduplicate_values(true),
         );
 
-        let (left, right) = data
+        let addr = vec!["abcd", "abcd", "efgh"]
+            .serialize(&mut serializer)
+            .unwrap();
+
+        let list = data
+            .inner()
             .get_data()
-            .get(*list.get(2).unwrap())
+            .get(addr)
             .unwrap()
-            .as_pair()
-            .unwrap();
+            .as_list()
+            .unwrap()
+            .0;
 
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0], list[1]);
+        assert_ne!(list[0], list[2]);
         assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("three"))
+            data.inner().get_data().get(list[0]).unwrap(),
+            &SimpleData::CharList("abcd".to_string())
         );
         assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            data.inner().get_data().get(list[2]).unwrap(),
+            &SimpleData::CharList("efgh".to_string())
         );
     }
 
     #[test]
-    fn serialize_struct_variant_with_short_name() {
-        use serde::ser::SerializeStructVariant;
+    fn serialize_struct_deduplicated() {
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Values {
+            one: i32,
+            two: i32,
+            three: String,
+            four: String,
+        }
 
-        let mut data = SimpleGarnishData::new();
+        let mut data = UncachedGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+            GarnishSerializationOptions::new().deduplicate_values(true),
         );
 
-        let mut serializer = serializer
-            .serialize_struct_variant("MyEnum", 100, "MyStruct", 3)
-            .unwrap();
-
-        serializer.serialize_field("one", &100).unwrap();
-        serializer.serialize_field("two", &200).unwrap();
-        serializer.serialize_field("three", &300).unwrap();
+        let addr = Values {
+            one: 10,
+            two: 10,
+            three: "abcd".to_string(),
+            four: "abcd".to_string(),
+        }
+        .serialize(&mut serializer)
+        .unwrap();
 
-        let addr = serializer.end().unwrap();
+        let data = data.inner().get_data();
+        let list = data.get(addr).unwrap().as_list().unwrap().0;
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let (_, one) = data.get(list[0]).unwrap().as_pair().unwrap();
+        let (_, two) = data.get(list[1]).unwrap().as_pair().unwrap();
+        let (_, three) = data.get(list[2]).unwrap().as_pair().unwrap();
+        let (_, four) = data.get(list[3]).unwrap().as_pair().unwrap();
 
+        assert_eq!(one, two);
+        assert_eq!(three, four);
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyStruct"))
+            data.get(one).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(10))
+        );
+        assert_eq!(
+            data.get(three).unwrap(),
+            &SimpleData::CharList("abcd".to_string())
         );
+    }
+
+    #[test]
+    fn serialize_sequence_not_deduplicated() {
+        use crate::test_data::UncachedGarnishData;
+        use serde::Serialize;
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let addr = vec!["abcd", "abcd"].serialize(&mut serializer).unwrap();
 
         let list = data
+            .inner()
             .get_data()
-            .get(*list.get(1).unwrap())
+            .get(addr)
             .unwrap()
             .as_list()
             .unwrap()
             .0;
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(0).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+        assert_eq!(list.len(), 2);
+        assert_ne!(list[0], list[1]);
+    }
 
-        assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("one"))
+    fn association_keys(data: &SimpleGarnishData, addr: usize) -> Vec<SimpleData> {
+        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        list.iter()
+            .map(|item| {
+                let (left, _) = data.get_data().get(*item).unwrap().as_pair().unwrap();
+                data.get_data().get(left).unwrap().clone()
+            })
+            .collect()
+    }
+
+    fn symbols(names: &[&str]) -> Vec<SimpleData> {
+        names
+            .iter()
+            .map(|name| SimpleData::Symbol(symbol_value(name)))
+            .collect()
+    }
+
+    #[test]
+    fn serialize_map_sorted_keys() {
+        use serde::ser::SerializeMap;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().map_key_ordering(MapKeyOrdering::Sorted),
         );
+
+        let mut serializer = serializer.serialize_map(None).unwrap();
+
+        serializer.serialize_key("two").unwrap();
+        serializer.serialize_value(&200).unwrap();
+        serializer.serialize_key("one").unwrap();
+        serializer.serialize_value(&100).unwrap();
+        serializer.serialize_key("three").unwrap();
+        serializer.serialize_value(&300).unwrap();
+
+        let addr = serializer.end().unwrap();
+
         assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
+            association_keys(&data, addr),
+            symbols(&["one", "three", "two"])
         );
+    }
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+    #[test]
+    fn serialize_map_sorted_number_keys() {
+        use serde::ser::SerializeMap;
 
-        assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("two"))
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .map_key_ordering(MapKeyOrdering::Sorted)
+                .map_key_behavior(crate::MapKeyBehavior::PreserveType),
         );
+
+        let mut serializer = serializer.serialize_map(None).unwrap();
+
+        serializer.serialize_key("b").unwrap();
+        serializer.serialize_value(&1).unwrap();
+        serializer.serialize_key(&100).unwrap();
+        serializer.serialize_value(&2).unwrap();
+        serializer.serialize_key(&-5).unwrap();
+        serializer.serialize_value(&3).unwrap();
+        serializer.serialize_key(&9).unwrap();
+        serializer.serialize_value(&4).unwrap();
+        serializer.serialize_key("a").unwrap();
+        serializer.serialize_value(&5).unwrap();
+
+        let addr = serializer.end().unwrap();
+
         assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+            association_keys(&data, addr),
+            vec![
+                SimpleData::Number(SimpleNumber::Integer(-5)),
+                SimpleData::Number(SimpleNumber::Integer(9)),
+                SimpleData::Number(SimpleNumber::Integer(100)),
+                SimpleData::CharList("a".to_string()),
+                SimpleData::CharList("b".to_string()),
+            ]
         );
+    }
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(2).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+    #[test]
+    fn serialize_map_sorted_number_keys_adds_no_char_lists() {
+        use crate::test_data::UncachedGarnishData;
+        use serde::ser::SerializeMap;
+
+        let mut data = UncachedGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .map_key_ordering(MapKeyOrdering::Sorted)
+                .map_key_behavior(crate::MapKeyBehavior::PreserveType),
+        );
+
+        let mut serializer = serializer.serialize_map(None).unwrap();
+
+        serializer.serialize_key(&100).unwrap();
+        serializer.serialize_value(&1).unwrap();
+        serializer.serialize_key(&9).unwrap();
+        serializer.serialize_value(&2).unwrap();
+
+        let addr = serializer.end().unwrap();
 
         assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("three"))
+            association_keys(data.inner(), addr),
+            vec![
+                SimpleData::Number(SimpleNumber::Integer(9)),
+                SimpleData::Number(SimpleNumber::Integer(100)),
+            ]
+        );
+        let values = data.inner().get_data();
+        assert!(!(0..values.len()).any(|i| matches!(values.get(i), Some(SimpleData::CharList(_)))));
+    }
+
+    #[test]
+    fn serialize_map_sorted_variant_keys_by_name() {
+        use serde::ser::SerializeMap;
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        enum Key {
+            Zeta,
+            Alpha,
+        }
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().map_key_ordering(MapKeyOrdering::Sorted),
         );
+
+        let mut serializer = serializer.serialize_map(None).unwrap();
+
+        serializer.serialize_key(&Key::Zeta).unwrap();
+        serializer.serialize_value(&1).unwrap();
+        serializer.serialize_key(&Key::Alpha).unwrap();
+        serializer.serialize_value(&2).unwrap();
+
+        let addr = serializer.end().unwrap();
+
         assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            association_keys(&data, addr),
+            symbols(&["Key::Alpha", "Key::Zeta"])
         );
     }
 
     #[test]
-    fn serialize_struct_variant_with_index_name() {
-        use serde::ser::SerializeStructVariant;
+    fn serialize_map_preserve_number_keys() {
+        use serde::ser::SerializeMap;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+            GarnishSerializationOptions::new()
+                .map_key_behavior(crate::MapKeyBehavior::PreserveNumbers),
         );
 
-        let mut serializer = serializer
-            .serialize_struct_variant("MyEnum", 100, "MyStruct", 3)
-            .unwrap();
+        let mut serializer = serializer.serialize_map(None).unwrap();
 
-        serializer.serialize_field("one", &100).unwrap();
-        serializer.serialize_field("two", &200).unwrap();
-        serializer.serialize_field("three", &300).unwrap();
+        serializer.serialize_key("a").unwrap();
+        serializer.serialize_value(&1).unwrap();
+        serializer.serialize_key(&100).unwrap();
+        serializer.serialize_value(&2).unwrap();
 
         let addr = serializer.end().unwrap();
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
-
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
+            association_keys(&data, addr),
+            vec![
+                SimpleData::Symbol(symbol_value("a")),
+                SimpleData::Number(SimpleNumber::Integer(100)),
+            ]
         );
+    }
 
-        let list = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_list()
-            .unwrap()
-            .0;
+    #[test]
+    fn serialize_hash_map_sorted_keys_is_deterministic() {
+        use serde::Serialize;
+        use std::collections::HashMap;
+
+        let serialize = |map: &HashMap<String, i32>| {
+            let mut data = SimpleGarnishData::new();
+            let mut serializer = GarnishDataSerializer::new_with_options(
+                &mut data,
+                GarnishSerializationOptions::new().map_key_ordering(MapKeyOrdering::Sorted),
+            );
+            let addr = map.serialize(&mut serializer).unwrap();
+            association_keys(&data, addr)
+        };
+
+        let entries: Vec<(String, i32)> = (0..50).map(|i| (format!("key{}", i), i)).collect();
+        // separate maps have separate hashers, so they are iterated in different orders
+        let first: HashMap<String, i32> = entries.iter().cloned().collect();
+        let second: HashMap<String, i32> = entries.iter().rev().cloned().collect();
+
+        assert_eq!(serialize(&first), serialize(&second));
+    }
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(0).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+    fn id_first(a: &str, b: &str) -> std::cmp::Ordering {
+        (a != "id", a).cmp(&(b != "id", b))
+    }
 
-        assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("one"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
+    #[test]
+    fn serialize_struct_custom_key_ordering() {
+        use serde::ser::SerializeStruct;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new().map_key_ordering(MapKeyOrdering::Custom(id_first)),
         );
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+        let mut serializer = serializer.serialize_struct("Person", 3).unwrap();
 
-        assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("two"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
-        );
+        serializer.serialize_field("name", "Bob").unwrap();
+        serializer.serialize_field("id", &10).unwrap();
+        serializer.serialize_field("age", &30).unwrap();
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(2).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+        let addr = serializer.end().unwrap();
 
         assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("three"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            association_keys(&data, addr),
+            symbols(&["id", "age", "name"])
         );
     }
 
     #[test]
-    fn serialize_tuple_variant_with_full_name() {
-        use serde::ser::SerializeTupleVariant;
+    fn serialize_struct_after_struct_variant_is_not_wrapped() {
+        use serde::ser::{SerializeStruct, SerializeStructVariant};
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new(&mut data);
 
-        let mut serializer = serializer
-            .serialize_tuple_variant("MyEnum", 100, "Type1", 3)
+        let mut variant = serializer
+            .serialize_struct_variant("MyEnum", 0, "MyStruct", 1)
             .unwrap();
+        SerializeStructVariant::serialize_field(&mut variant, "one", &100).unwrap();
+        SerializeStructVariant::end(variant).unwrap();
 
-        serializer.serialize_field(&100).unwrap();
-        serializer.serialize_field(&200).unwrap();
-        serializer.serialize_field(&300).unwrap();
+        let mut s = serializer.serialize_struct("Plain", 1).unwrap();
+        SerializeStruct::serialize_field(&mut s, "two", &200).unwrap();
+        let addr = SerializeStruct::end(s).unwrap();
 
-        let addr = serializer.end().unwrap();
+        assert_eq!(association_keys(&data, addr), symbols(&["two"]));
+    }
 
+    fn typing_pair_value(data: &SimpleGarnishData, addr: usize) -> SimpleData {
         let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let (_, right) = data.get_data().get(list[0]).unwrap().as_pair().unwrap();
+        data.get_data().get(right).unwrap().clone()
+    }
 
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("MyEnum::Type1"))
+    #[test]
+    fn serialize_struct_with_typing_pair() {
+        use serde::ser::SerializeStruct;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTypingAsPair),
         );
 
-        let list = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_list()
-            .unwrap()
-            .0;
+        let mut serializer = serializer.serialize_struct("Person", 2).unwrap();
+
+        serializer.serialize_field("name", "Bob").unwrap();
+        serializer.serialize_field("age", &30).unwrap();
+
+        let addr = serializer.end().unwrap();
 
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+            association_keys(&data, addr),
+            symbols(&["__type", "name", "age"])
         );
         assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            typing_pair_value(&data, addr),
+            SimpleData::Symbol(symbol_value("Person"))
         );
     }
 
     #[test]
-    fn serialize_tuple_variant_with_short_name() {
-        use serde::ser::SerializeTupleVariant;
+    fn serialize_struct_with_typing_pair_stays_first_when_sorted() {
+        use serde::ser::SerializeStruct;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Short),
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTypingAsPair)
+                .type_tag_key("type")
+                .map_key_ordering(MapKeyOrdering::Sorted),
         );
 
-        let mut serializer = serializer
-            .serialize_tuple_variant("MyEnum", 100, "Type1", 3)
-            .unwrap();
+        let mut serializer = serializer.serialize_struct("Person", 2).unwrap();
 
-        serializer.serialize_field(&100).unwrap();
-        serializer.serialize_field(&200).unwrap();
-        serializer.serialize_field(&300).unwrap();
+        serializer.serialize_field("name", "Bob").unwrap();
+        serializer.serialize_field("age", &30).unwrap();
 
         let addr = serializer.end().unwrap();
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
-
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Symbol(symbol_value("Type1"))
-        );
-
-        let list = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_list()
-            .unwrap()
-            .0;
-
-        assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
-        );
-        assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
-        );
         assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            association_keys(&data, addr),
+            symbols(&["type", "age", "name"])
         );
     }
 
     #[test]
-    fn serialize_tuple_variant_with_index_name() {
-        use serde::ser::SerializeTupleVariant;
+    fn serialize_tuple_struct_with_typing_pair() {
+        use serde::ser::SerializeTupleStruct;
 
         let mut data = SimpleGarnishData::new();
         let mut serializer = GarnishDataSerializer::new_with_options(
             &mut data,
-            GarnishSerializationOptions::new().variant_name_behavior(VariantNameBehavior::Index),
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTypingAsPair)
+                .type_tag_key("type"),
         );
 
-        let mut serializer = serializer
-            .serialize_tuple_variant("MyEnum", 100, "Type1", 3)
-            .unwrap();
+        let mut serializer = serializer.serialize_tuple_struct("MyTuple", 2).unwrap();
 
         serializer.serialize_field(&100).unwrap();
         serializer.serialize_field(&200).unwrap();
-        serializer.serialize_field(&300).unwrap();
 
         let addr = serializer.end().unwrap();
 
         let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+        let (left, _) = data.get_data().get(list[0]).unwrap().as_pair().unwrap();
 
+        assert_eq!(list.len(), 3);
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
+            data.get_data().get(left).unwrap(),
+            &SimpleData::Symbol(symbol_value("type"))
         );
-
-        let list = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_list()
-            .unwrap()
-            .0;
-
         assert_eq!(
-            data.get_data().get(*list.get(0).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
+            typing_pair_value(&data, addr),
+            SimpleData::Symbol(symbol_value("MyTuple"))
         );
         assert_eq!(
-            data.get_data().get(*list.get(1).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+            data.get_data().get(list[1]).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(100))
         );
         assert_eq!(
-            data.get_data().get(*list.get(2).unwrap()).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            data.get_data().get(list[2]).unwrap(),
+            &SimpleData::Number(SimpleNumber::Integer(200))
         );
     }
 
     #[test]
-    fn serialize_map() {
-        use serde::ser::SerializeMap;
-
+    fn serialize_unit_struct_with_typing_pair() {
         let mut data = SimpleGarnishData::new();
-        let mut serializer = GarnishDataSerializer::new(&mut data);
+        let mut serializer = GarnishDataSerializer::new_with_options(
+            &mut data,
+            GarnishSerializationOptions::new()
+                .struct_typing_behavior(StructBehavior::IncludeTypingAsPair),
+        );
 
-        let mut serializer = serializer.serialize_map(None).unwrap();
+        let addr = serializer.serialize_unit_struct("Marker").unwrap();
 
-        serializer.serialize_key("one").unwrap();
-        serializer.serialize_value(&100).unwrap();
-        serializer.serialize_key("two").unwrap();
-        serializer.serialize_value(&200).unwrap();
-        serializer.serialize_key("three").unwrap();
-        serializer.serialize_value(&300).unwrap();
+        assert_eq!(association_keys(&data, addr), symbols(&["__type"]));
+        assert_eq!(
+            typing_pair_value(&data, addr),
+            SimpleData::Symbol(symbol_value("Marker"))
+        );
+    }
 
-        let addr = serializer.end().unwrap();
+    // declares a tuple length that doesn't match the elements it serializes
+    struct MiscountedTuple {
+        declared: usize,
+        elements: usize,
+    }
 
-        let list = data.get_data().get(addr).unwrap().as_list().unwrap().0;
+    impl serde::Serialize for MiscountedTuple {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            use serde::ser::SerializeTuple;
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(0).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+            let mut t = serializer.serialize_tuple(self.declared)?;
+            for i in 0..self.elements {
+                t.serialize_element(&i)?;
+            }
+            t.end()
+        }
+    }
 
-        assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("one"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(100))
-        );
+    #[test]
+    fn serialize_tuple_with_too_many_elements() {
+        use serde::Serialize;
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(1).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let err = MiscountedTuple {
+            declared: 1,
+            elements: 2,
+        }
+        .serialize(&mut serializer)
+        .unwrap_err();
 
         assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("two"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(200))
+            err.to_string(),
+            "Tuple declared with length 1 received more elements."
         );
+    }
 
-        let (left, right) = data
-            .get_data()
-            .get(*list.get(2).unwrap())
-            .unwrap()
-            .as_pair()
-            .unwrap();
+    #[test]
+    fn serialize_tuple_with_too_few_elements() {
+        use serde::Serialize;
+
+        let mut data = SimpleGarnishData::new();
+        let mut serializer = GarnishDataSerializer::new(&mut data);
+
+        let err = MiscountedTuple {
+            declared: 3,
+            elements: 2,
+        }
+        .serialize(&mut serializer)
+        .unwrap_err();
 
         assert_eq!(
-            data.get_data().get(left).unwrap(),
-            &SimpleData::Symbol(symbol_value("three"))
-        );
-        assert_eq!(
-            data.get_data().get(right).unwrap(),
-            &SimpleData::Number(SimpleNumber::Integer(300))
+            err.to_string(),
+            "Tuple declared with length 3 received 2 elements."
         );
     }
 }
@@ -0,0 +1,52 @@
+use garnish_lang_simple_data::{DataError, SimpleData, SimpleGarnishData};
+use garnish_lang_traits::GarnishData;
+
+use crate::{GarnishBorrowedData, GarnishStringConversions};
+
+// conversions for the simple data backend,
+// so it can be used with read only and borrowing deserializers and as the source of transcode
+impl GarnishStringConversions for SimpleGarnishData {
+    fn create_string(&self, addr: usize) -> Result<String, DataError> {
+        match self.get_data().get(addr) {
+            Some(SimpleData::CharList(s)) => Ok(s.clone()),
+            Some(SimpleData::Symbol(sym)) => match self.get_data().get_symbol(*sym) {
+                Some(s) => Ok(s.clone()),
+                None => Err(DataError::from(format!("No name for symbol {}", sym))),
+            },
+            Some(SimpleData::Concatenation(left, right)) => Ok(format!(
+                "{}{}",
+                self.create_string(*left)?,
+                self.create_string(*right)?
+            )),
+            Some(SimpleData::Slice(list, range)) => {
+                let (start, end) = self.get_range(*range)?;
+                let start: usize = self.get_number(start)?.into();
+                let end: usize = self.get_number(end)?.into();
+                Ok(self
+                    .create_string(*list)?
+                    .chars()
+                    .skip(start)
+                    .take(end - start + 1)
+                    .collect())
+            }
+            Some(v) => Err(DataError::from(format!("No string conversion for {:?}", v))),
+            None => Err(DataError::from(format!("No data at addr {}", addr))),
+        }
+    }
+}
+
+impl GarnishBorrowedData for SimpleGarnishData {
+    fn get_char_list_str(&self, addr: usize) -> Option<&str> {
+        match self.get_data().get(addr) {
+            Some(SimpleData::CharList(s)) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    fn get_byte_list_slice(&self, addr: usize) -> Option<&[u8]> {
+        match self.get_data().get(addr) {
+            Some(SimpleData::ByteList(bytes)) => Some(bytes.as_slice()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,1179 @@
+use garnish_lang_simple_data::{
+    DataError, NumberIterator, SimpleData, SimpleGarnishData, SimpleNumber, SizeIterator,
+};
+use garnish_lang_traits::{
+    GarnishData, GarnishDataType, GarnishNumber, Instruction, TypeConstants,
+};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Wrapper around [`SimpleGarnishData`] that always appends new values instead of reusing
+/// addresses of identical values. Used to test behavior that would be hidden by the
+/// caching done in [`SimpleGarnishData`].
+///
+/// Lists are collected here until ended, so unlike [`SimpleGarnishData`] lists can be nested.
+/// Char list lengths are counted in chars instead of utf-8 bytes.
+pub struct UncachedGarnishData {
+    inner: SimpleGarnishData,
+    // declared length and items of each list being created
+    lists: Vec<(usize, Vec<(usize, bool)>)>,
+    validate_list_len: bool,
+    f32_floats: bool,
+}
+
+impl UncachedGarnishData {
+    pub fn new() -> Self {
+        Self {
+            inner: SimpleGarnishData::new(),
+            lists: vec![],
+            validate_list_len: false,
+            f32_floats: false,
+        }
+    }
+
+    /// Fail when more items are added to a list than the length given to `start_list`.
+    pub fn new_validating_list_len() -> Self {
+        Self {
+            validate_list_len: true,
+            ..Self::new()
+        }
+    }
+
+    /// Store floats with only f32 precision, to test values the data object can't keep exactly.
+    pub fn new_with_f32_floats() -> Self {
+        Self {
+            f32_floats: true,
+            ..Self::new()
+        }
+    }
+
+    pub fn inner(&self) -> &SimpleGarnishData {
+        &self.inner
+    }
+
+    fn push(&mut self, value: SimpleData) -> Result<usize, DataError> {
+        self.inner.get_data_mut().push(value);
+        Ok(self.inner.get_data().len() - 1)
+    }
+}
+
+impl GarnishData for UncachedGarnishData {
+    type Error = DataError;
+    type Symbol = u64;
+    type Byte = u8;
+    type Char = char;
+    type Number = SimpleNumber;
+    type Size = usize;
+    type SizeIterator = SizeIterator;
+    type NumberIterator = NumberIterator;
+    type InstructionIterator = SizeIterator;
+    type DataIndexIterator = SizeIterator;
+    type ValueIndexInterator = SizeIterator;
+    type RegisterIndexInterator = SizeIterator;
+    type JumpTableIndexInterator = SizeIterator;
+    type JumpPathIndexInterator = SizeIterator;
+    type ListIndexInterator = NumberIterator;
+
+    fn get_data_len(&self) -> usize {
+        self.inner.get_data_len()
+    }
+
+    fn get_data_iter(&self) -> SizeIterator {
+        self.inner.get_data_iter()
+    }
+
+    fn get_value_stack_len(&self) -> usize {
+        self.inner.get_value_stack_len()
+    }
+
+    fn push_value_stack(&mut self, addr: usize) -> Result<(), DataError> {
+        self.inner.push_value_stack(addr)
+    }
+
+    fn pop_value_stack(&mut self) -> Option<usize> {
+        self.inner.pop_value_stack()
+    }
+
+    fn get_value(&self, addr: usize) -> Option<usize> {
+        self.inner.get_value(addr)
+    }
+
+    fn get_value_mut(&mut self, addr: usize) -> Option<&mut usize> {
+        self.inner.get_value_mut(addr)
+    }
+
+    fn get_current_value(&self) -> Option<usize> {
+        self.inner.get_current_value()
+    }
+
+    fn get_current_value_mut(&mut self) -> Option<&mut usize> {
+        self.inner.get_current_value_mut()
+    }
+
+    fn get_value_iter(&self) -> SizeIterator {
+        self.inner.get_value_iter()
+    }
+
+    fn get_data_type(&self, addr: usize) -> Result<GarnishDataType, DataError> {
+        self.inner.get_data_type(addr)
+    }
+
+    fn get_number(&self, addr: usize) -> Result<SimpleNumber, DataError> {
+        self.inner.get_number(addr)
+    }
+
+    fn get_type(&self, addr: usize) -> Result<GarnishDataType, DataError> {
+        self.inner.get_type(addr)
+    }
+
+    fn get_char(&self, addr: usize) -> Result<char, DataError> {
+        self.inner.get_char(addr)
+    }
+
+    fn get_byte(&self, addr: usize) -> Result<u8, DataError> {
+        self.inner.get_byte(addr)
+    }
+
+    fn get_symbol(&self, addr: usize) -> Result<u64, DataError> {
+        self.inner.get_symbol(addr)
+    }
+
+    fn get_expression(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_expression(addr)
+    }
+
+    fn get_external(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_external(addr)
+    }
+
+    fn get_pair(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_pair(addr)
+    }
+
+    fn get_concatenation(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_concatenation(addr)
+    }
+
+    fn get_range(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_range(addr)
+    }
+
+    fn get_slice(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_slice(addr)
+    }
+
+    fn get_list_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_list_len(addr)
+    }
+
+    fn get_list_item(&self, list_addr: usize, item_addr: SimpleNumber) -> Result<usize, DataError> {
+        self.inner.get_list_item(list_addr, item_addr)
+    }
+
+    fn get_list_associations_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_list_associations_len(addr)
+    }
+
+    fn get_list_association(
+        &self,
+        list_addr: usize,
+        item_addr: SimpleNumber,
+    ) -> Result<usize, DataError> {
+        self.inner.get_list_association(list_addr, item_addr)
+    }
+
+    fn get_list_item_with_symbol(
+        &self,
+        list_addr: usize,
+        sym: u64,
+    ) -> Result<Option<usize>, DataError> {
+        self.inner.get_list_item_with_symbol(list_addr, sym)
+    }
+
+    fn get_list_items_iter(&self, list_addr: usize) -> NumberIterator {
+        self.inner.get_list_items_iter(list_addr)
+    }
+
+    fn get_list_associations_iter(&self, list_addr: usize) -> NumberIterator {
+        self.inner.get_list_associations_iter(list_addr)
+    }
+
+    // counted in chars, SimpleGarnishData reports the length in utf-8 bytes
+    fn get_char_list_len(&self, addr: usize) -> Result<usize, DataError> {
+        match self.inner.get_data().get(addr) {
+            Some(SimpleData::CharList(s)) => Ok(s.chars().count()),
+            _ => self.inner.get_char_list_len(addr),
+        }
+    }
+
+    fn get_char_list_item(&self, addr: usize, item_index: SimpleNumber) -> Result<char, DataError> {
+        self.inner.get_char_list_item(addr, item_index)
+    }
+
+    fn get_char_list_iter(&self, list_addr: usize) -> NumberIterator {
+        let len = self.get_char_list_len(list_addr).unwrap_or(0);
+        NumberIterator::new(SimpleNumber::Integer(0), Self::size_to_number(len))
+    }
+
+    fn get_byte_list_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_byte_list_len(addr)
+    }
+
+    fn get_byte_list_item(&self, addr: usize, item_index: SimpleNumber) -> Result<u8, DataError> {
+        self.inner.get_byte_list_item(addr, item_index)
+    }
+
+    fn get_byte_list_iter(&self, list_addr: usize) -> NumberIterator {
+        self.inner.get_byte_list_iter(list_addr)
+    }
+
+    fn add_unit(&mut self) -> Result<usize, DataError> {
+        self.push(SimpleData::Unit)
+    }
+
+    fn add_true(&mut self) -> Result<usize, DataError> {
+        self.inner.add_true()
+    }
+
+    fn add_false(&mut self) -> Result<usize, DataError> {
+        self.inner.add_false()
+    }
+
+    fn add_number(&mut self, value: SimpleNumber) -> Result<usize, DataError> {
+        let value = match value {
+            SimpleNumber::Float(f) if self.f32_floats => SimpleNumber::Float(f as f32 as f64),
+            v => v,
+        };
+        self.push(SimpleData::Number(value))
+    }
+
+    fn add_type(&mut self, value: GarnishDataType) -> Result<usize, DataError> {
+        self.push(SimpleData::Type(value))
+    }
+
+    fn add_char(&mut self, value: char) -> Result<usize, DataError> {
+        self.push(SimpleData::Char(value))
+    }
+
+    fn add_byte(&mut self, value: u8) -> Result<usize, DataError> {
+        self.push(SimpleData::Byte(value))
+    }
+
+    fn add_symbol(&mut self, value: u64) -> Result<usize, DataError> {
+        self.push(SimpleData::Symbol(value))
+    }
+
+    fn add_expression(&mut self, value: usize) -> Result<usize, DataError> {
+        self.push(SimpleData::Expression(value))
+    }
+
+    fn add_external(&mut self, value: usize) -> Result<usize, DataError> {
+        self.push(SimpleData::External(value))
+    }
+
+    fn add_pair(&mut self, value: (usize, usize)) -> Result<usize, DataError> {
+        self.inner.add_pair(value)
+    }
+
+    fn add_concatenation(&mut self, left: usize, right: usize) -> Result<usize, DataError> {
+        self.inner.add_concatenation(left, right)
+    }
+
+    fn add_range(&mut self, start: usize, end: usize) -> Result<usize, DataError> {
+        self.inner.add_range(start, end)
+    }
+
+    fn add_slice(&mut self, list: usize, range: usize) -> Result<usize, DataError> {
+        self.inner.add_slice(list, range)
+    }
+
+    fn start_list(&mut self, len: usize) -> Result<(), DataError> {
+        self.lists.push((len, vec![]));
+        Ok(())
+    }
+
+    fn add_to_list(&mut self, addr: usize, is_associative: bool) -> Result<(), DataError> {
+        let validate = self.validate_list_len;
+        match self.lists.last_mut() {
+            None => Err(DataError::from(
+                "Not currently creating a list.".to_string(),
+            )),
+            Some((declared, items)) if validate && items.len() >= *declared => {
+                Err(DataError::from(format!(
+                    "List declared with length {} but more items were added.",
+                    declared
+                )))
+            }
+            Some((_, items)) => {
+                items.push((addr, is_associative));
+                Ok(())
+            }
+        }
+    }
+
+    fn end_list(&mut self) -> Result<usize, DataError> {
+        let (len, items) = self.lists.pop().ok_or(DataError::from(
+            "Not currently creating a list.".to_string(),
+        ))?;
+
+        self.inner.start_list(len)?;
+        for (addr, is_associative) in items {
+            self.inner.add_to_list(addr, is_associative)?;
+        }
+        self.inner.end_list()
+    }
+
+    fn start_char_list(&mut self) -> Result<(), DataError> {
+        self.inner.start_char_list()
+    }
+
+    fn add_to_char_list(&mut self, c: char) -> Result<(), DataError> {
+        self.inner.add_to_char_list(c)
+    }
+
+    fn end_char_list(&mut self) -> Result<usize, DataError> {
+        // let inner data build the list, then copy it to a new address
+        let addr = self.inner.end_char_list()?;
+        let value = self.inner.get_data().get(addr).cloned().unwrap();
+        self.push(value)
+    }
+
+    fn start_byte_list(&mut self) -> Result<(), DataError> {
+        self.inner.start_byte_list()
+    }
+
+    fn add_to_byte_list(&mut self, c: u8) -> Result<(), DataError> {
+        self.inner.add_to_byte_list(c)
+    }
+
+    fn end_byte_list(&mut self) -> Result<usize, DataError> {
+        let addr = self.inner.end_byte_list()?;
+        let value = self.inner.get_data().get(addr).cloned().unwrap();
+        self.push(value)
+    }
+
+    fn get_register_len(&self) -> usize {
+        self.inner.get_register_len()
+    }
+
+    fn push_register(&mut self, addr: usize) -> Result<(), DataError> {
+        self.inner.push_register(addr)
+    }
+
+    fn get_register(&self, addr: usize) -> Option<usize> {
+        self.inner.get_register(addr)
+    }
+
+    fn pop_register(&mut self) -> Option<usize> {
+        self.inner.pop_register()
+    }
+
+    fn get_register_iter(&self) -> SizeIterator {
+        self.inner.get_register_iter()
+    }
+
+    fn get_instruction_len(&self) -> usize {
+        self.inner.get_instruction_len()
+    }
+
+    fn push_instruction(
+        &mut self,
+        instruction: Instruction,
+        data: Option<usize>,
+    ) -> Result<usize, DataError> {
+        self.inner.push_instruction(instruction, data)
+    }
+
+    fn get_instruction(&self, addr: usize) -> Option<(Instruction, Option<usize>)> {
+        self.inner.get_instruction(addr)
+    }
+
+    fn get_instruction_iter(&self) -> SizeIterator {
+        self.inner.get_instruction_iter()
+    }
+
+    fn get_instruction_cursor(&self) -> usize {
+        self.inner.get_instruction_cursor()
+    }
+
+    fn set_instruction_cursor(&mut self, addr: usize) -> Result<(), DataError> {
+        self.inner.set_instruction_cursor(addr)
+    }
+
+    fn get_jump_table_len(&self) -> usize {
+        self.inner.get_jump_table_len()
+    }
+
+    fn push_jump_point(&mut self, index: usize) -> Result<(), DataError> {
+        self.inner.push_jump_point(index)
+    }
+
+    fn get_jump_point(&self, index: usize) -> Option<usize> {
+        self.inner.get_jump_point(index)
+    }
+
+    fn get_jump_point_mut(&mut self, index: usize) -> Option<&mut usize> {
+        self.inner.get_jump_point_mut(index)
+    }
+
+    fn get_jump_table_iter(&self) -> SizeIterator {
+        self.inner.get_jump_table_iter()
+    }
+
+    fn push_jump_path(&mut self, index: usize) -> Result<(), DataError> {
+        self.inner.push_jump_path(index)
+    }
+
+    fn pop_jump_path(&mut self) -> Option<usize> {
+        self.inner.pop_jump_path()
+    }
+
+    fn get_jump_path_iter(&self) -> SizeIterator {
+        self.inner.get_jump_path_iter()
+    }
+
+    fn size_to_number(from: usize) -> SimpleNumber {
+        <SimpleGarnishData as GarnishData>::size_to_number(from)
+    }
+
+    fn number_to_size(from: SimpleNumber) -> Option<usize> {
+        <SimpleGarnishData as GarnishData>::number_to_size(from)
+    }
+
+    fn number_to_char(from: SimpleNumber) -> Option<char> {
+        <SimpleGarnishData as GarnishData>::number_to_char(from)
+    }
+
+    fn number_to_byte(from: SimpleNumber) -> Option<u8> {
+        <SimpleGarnishData as GarnishData>::number_to_byte(from)
+    }
+
+    fn char_to_number(from: char) -> Option<SimpleNumber> {
+        <SimpleGarnishData as GarnishData>::char_to_number(from)
+    }
+
+    fn char_to_byte(from: char) -> Option<u8> {
+        <SimpleGarnishData as GarnishData>::char_to_byte(from)
+    }
+
+    fn byte_to_number(from: u8) -> Option<SimpleNumber> {
+        <SimpleGarnishData as GarnishData>::byte_to_number(from)
+    }
+
+    fn byte_to_char(from: u8) -> Option<char> {
+        <SimpleGarnishData as GarnishData>::byte_to_char(from)
+    }
+
+    fn add_char_list_from(&mut self, from: usize) -> Result<usize, DataError> {
+        let addr = self.inner.add_char_list_from(from)?;
+        let value = self.inner.get_data().get(addr).cloned().unwrap();
+        self.push(value)
+    }
+
+    fn add_byte_list_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_byte_list_from(from)
+    }
+
+    fn add_symbol_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_symbol_from(from)
+    }
+
+    fn add_byte_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_byte_from(from)
+    }
+
+    fn add_number_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_number_from(from)
+    }
+
+    fn parse_number(from: &str) -> Result<SimpleNumber, DataError> {
+        <SimpleGarnishData as GarnishData>::parse_number(from)
+    }
+
+    fn parse_symbol(from: &str) -> Result<u64, DataError> {
+        <SimpleGarnishData as GarnishData>::parse_symbol(from)
+    }
+
+    fn parse_char(from: &str) -> Result<char, DataError> {
+        <SimpleGarnishData as GarnishData>::parse_char(from)
+    }
+
+    fn parse_byte(from: &str) -> Result<u8, DataError> {
+        <SimpleGarnishData as GarnishData>::parse_byte(from)
+    }
+
+    fn parse_char_list(from: &str) -> Result<Vec<char>, DataError> {
+        <SimpleGarnishData as GarnishData>::parse_char_list(from)
+    }
+
+    fn parse_byte_list(from: &str) -> Result<Vec<u8>, DataError> {
+        <SimpleGarnishData as GarnishData>::parse_byte_list(from)
+    }
+
+    fn parse_add_symbol(&mut self, from: &str) -> Result<usize, DataError> {
+        let sym = Self::parse_symbol(from)?;
+        self.inner.get_data_mut().insert_symbol(sym, from);
+        self.add_symbol(sym)
+    }
+
+    fn make_size_iterator_range(min: usize, max: usize) -> SizeIterator {
+        <SimpleGarnishData as GarnishData>::make_size_iterator_range(min, max)
+    }
+
+    fn make_number_iterator_range(min: SimpleNumber, max: SimpleNumber) -> NumberIterator {
+        <SimpleGarnishData as GarnishData>::make_number_iterator_range(min, max)
+    }
+}
+
+/// Number that holds 128 bit integers, to test backends with numbers wider than 64 bits.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum WideNumber {
+    Integer(i128),
+    Float(f64),
+}
+
+pub type WideNumberIterator = std::vec::IntoIter<WideNumber>;
+
+impl WideNumber {
+    fn from_simple(n: SimpleNumber) -> Self {
+        match n {
+            SimpleNumber::Integer(i) => WideNumber::Integer(i128::from(i)),
+            SimpleNumber::Float(f) => WideNumber::Float(f),
+        }
+    }
+
+    fn from_simple_iter(iter: NumberIterator) -> WideNumberIterator {
+        iter.map(WideNumber::from_simple)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    // integers beyond i32 are kept by WideGarnishData, inner data only stores an approximation
+    fn to_simple(self) -> SimpleNumber {
+        match self {
+            WideNumber::Integer(i) => match i32::try_from(i) {
+                Ok(i) => SimpleNumber::Integer(i),
+                Err(_) => SimpleNumber::Float(i as f64),
+            },
+            WideNumber::Float(f) => SimpleNumber::Float(f),
+        }
+    }
+
+    fn integers(self, rhs: Self, op: fn(i128, i128) -> Option<i128>) -> Option<Self> {
+        match (self, rhs) {
+            (WideNumber::Integer(l), WideNumber::Integer(r)) => op(l, r).map(WideNumber::Integer),
+            _ => None,
+        }
+    }
+}
+
+impl Default for WideNumber {
+    fn default() -> Self {
+        WideNumber::Integer(0)
+    }
+}
+
+impl Display for WideNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WideNumber::Integer(i) => write!(f, "{}", i),
+            WideNumber::Float(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl TypeConstants for WideNumber {
+    fn zero() -> Self {
+        WideNumber::Integer(0)
+    }
+
+    fn one() -> Self {
+        WideNumber::Integer(1)
+    }
+
+    fn max_value() -> Self {
+        WideNumber::Integer(i128::MAX)
+    }
+}
+
+impl GarnishNumber for WideNumber {
+    fn plus(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, i128::checked_add)
+    }
+
+    fn subtract(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, i128::checked_sub)
+    }
+
+    fn multiply(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, i128::checked_mul)
+    }
+
+    fn divide(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, i128::checked_div)
+    }
+
+    fn integer_divide(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, i128::checked_div)
+    }
+
+    fn power(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, |l, r| l.checked_pow(u32::try_from(r).ok()?))
+    }
+
+    fn remainder(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, i128::checked_rem)
+    }
+
+    fn absolute_value(self) -> Option<Self> {
+        self.integers(self, |l, _| l.checked_abs())
+    }
+
+    fn opposite(self) -> Option<Self> {
+        self.integers(self, |l, _| l.checked_neg())
+    }
+
+    fn increment(self) -> Option<Self> {
+        self.integers(self, |l, _| l.checked_add(1))
+    }
+
+    fn decrement(self) -> Option<Self> {
+        self.integers(self, |l, _| l.checked_sub(1))
+    }
+
+    fn bitwise_not(self) -> Option<Self> {
+        self.integers(self, |l, _| Some(!l))
+    }
+
+    fn bitwise_and(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, |l, r| Some(l & r))
+    }
+
+    fn bitwise_or(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, |l, r| Some(l | r))
+    }
+
+    fn bitwise_xor(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, |l, r| Some(l ^ r))
+    }
+
+    fn bitwise_shift_left(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, |l, r| l.checked_shl(u32::try_from(r).ok()?))
+    }
+
+    fn bitwise_shift_right(self, rhs: Self) -> Option<Self> {
+        self.integers(rhs, |l, r| l.checked_shr(u32::try_from(r).ok()?))
+    }
+}
+
+// conversions are casts, like those of SimpleNumber
+macro_rules! wide_number_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for WideNumber {
+                fn from(v: $t) -> Self {
+                    WideNumber::Integer(v as i128)
+                }
+            }
+
+            impl From<WideNumber> for $t {
+                fn from(n: WideNumber) -> Self {
+                    match n {
+                        WideNumber::Integer(i) => i as $t,
+                        WideNumber::Float(f) => f as $t,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+wide_number_conversions!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
+
+macro_rules! wide_number_float_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for WideNumber {
+                fn from(v: $t) -> Self {
+                    WideNumber::Float(v as f64)
+                }
+            }
+
+            impl From<WideNumber> for $t {
+                fn from(n: WideNumber) -> Self {
+                    match n {
+                        WideNumber::Integer(i) => i as $t,
+                        WideNumber::Float(f) => f as $t,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+wide_number_float_conversions!(f32, f64);
+
+/// Wrapper around [`UncachedGarnishData`] using [`WideNumber`], to test numbers wider than 64 bits.
+pub struct WideGarnishData {
+    inner: UncachedGarnishData,
+    // integers too wide for the numbers of inner data
+    wide_numbers: HashMap<usize, WideNumber>,
+}
+
+impl WideGarnishData {
+    pub fn new() -> Self {
+        Self {
+            inner: UncachedGarnishData::new(),
+            wide_numbers: HashMap::new(),
+        }
+    }
+}
+
+impl GarnishData for WideGarnishData {
+    type Error = DataError;
+    type Symbol = u64;
+    type Byte = u8;
+    type Char = char;
+    type Number = WideNumber;
+    type Size = usize;
+    type SizeIterator = SizeIterator;
+    type NumberIterator = WideNumberIterator;
+    type InstructionIterator = SizeIterator;
+    type DataIndexIterator = SizeIterator;
+    type ValueIndexInterator = SizeIterator;
+    type RegisterIndexInterator = SizeIterator;
+    type JumpTableIndexInterator = SizeIterator;
+    type JumpPathIndexInterator = SizeIterator;
+    type ListIndexInterator = WideNumberIterator;
+
+    fn get_data_len(&self) -> usize {
+        self.inner.get_data_len()
+    }
+
+    fn get_data_iter(&self) -> SizeIterator {
+        self.inner.get_data_iter()
+    }
+
+    fn get_value_stack_len(&self) -> usize {
+        self.inner.get_value_stack_len()
+    }
+
+    fn push_value_stack(&mut self, addr: usize) -> Result<(), DataError> {
+        self.inner.push_value_stack(addr)
+    }
+
+    fn pop_value_stack(&mut self) -> Option<usize> {
+        self.inner.pop_value_stack()
+    }
+
+    fn get_value(&self, addr: usize) -> Option<usize> {
+        self.inner.get_value(addr)
+    }
+
+    fn get_value_mut(&mut self, addr: usize) -> Option<&mut usize> {
+        self.inner.get_value_mut(addr)
+    }
+
+    fn get_current_value(&self) -> Option<usize> {
+        self.inner.get_current_value()
+    }
+
+    fn get_current_value_mut(&mut self) -> Option<&mut usize> {
+        self.inner.get_current_value_mut()
+    }
+
+    fn get_value_iter(&self) -> SizeIterator {
+        self.inner.get_value_iter()
+    }
+
+    fn get_data_type(&self, addr: usize) -> Result<GarnishDataType, DataError> {
+        self.inner.get_data_type(addr)
+    }
+
+    fn get_number(&self, addr: usize) -> Result<WideNumber, DataError> {
+        match self.wide_numbers.get(&addr) {
+            Some(n) => Ok(*n),
+            None => self.inner.get_number(addr).map(WideNumber::from_simple),
+        }
+    }
+
+    fn get_type(&self, addr: usize) -> Result<GarnishDataType, DataError> {
+        self.inner.get_type(addr)
+    }
+
+    fn get_char(&self, addr: usize) -> Result<char, DataError> {
+        self.inner.get_char(addr)
+    }
+
+    fn get_byte(&self, addr: usize) -> Result<u8, DataError> {
+        self.inner.get_byte(addr)
+    }
+
+    fn get_symbol(&self, addr: usize) -> Result<u64, DataError> {
+        self.inner.get_symbol(addr)
+    }
+
+    fn get_expression(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_expression(addr)
+    }
+
+    fn get_external(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_external(addr)
+    }
+
+    fn get_pair(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_pair(addr)
+    }
+
+    fn get_concatenation(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_concatenation(addr)
+    }
+
+    fn get_range(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_range(addr)
+    }
+
+    fn get_slice(&self, addr: usize) -> Result<(usize, usize), DataError> {
+        self.inner.get_slice(addr)
+    }
+
+    fn get_list_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_list_len(addr)
+    }
+
+    fn get_list_item(&self, list_addr: usize, item_addr: WideNumber) -> Result<usize, DataError> {
+        self.inner.get_list_item(list_addr, item_addr.to_simple())
+    }
+
+    fn get_list_associations_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_list_associations_len(addr)
+    }
+
+    fn get_list_association(
+        &self,
+        list_addr: usize,
+        item_addr: WideNumber,
+    ) -> Result<usize, DataError> {
+        self.inner
+            .get_list_association(list_addr, item_addr.to_simple())
+    }
+
+    fn get_list_item_with_symbol(
+        &self,
+        list_addr: usize,
+        sym: u64,
+    ) -> Result<Option<usize>, DataError> {
+        self.inner.get_list_item_with_symbol(list_addr, sym)
+    }
+
+    fn get_list_items_iter(&self, list_addr: usize) -> WideNumberIterator {
+        WideNumber::from_simple_iter(self.inner.get_list_items_iter(list_addr))
+    }
+
+    fn get_list_associations_iter(&self, list_addr: usize) -> WideNumberIterator {
+        WideNumber::from_simple_iter(self.inner.get_list_associations_iter(list_addr))
+    }
+
+    fn get_char_list_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_char_list_len(addr)
+    }
+
+    fn get_char_list_item(&self, addr: usize, item_index: WideNumber) -> Result<char, DataError> {
+        self.inner.get_char_list_item(addr, item_index.to_simple())
+    }
+
+    fn get_char_list_iter(&self, list_addr: usize) -> WideNumberIterator {
+        WideNumber::from_simple_iter(self.inner.get_char_list_iter(list_addr))
+    }
+
+    fn get_byte_list_len(&self, addr: usize) -> Result<usize, DataError> {
+        self.inner.get_byte_list_len(addr)
+    }
+
+    fn get_byte_list_item(&self, addr: usize, item_index: WideNumber) -> Result<u8, DataError> {
+        self.inner.get_byte_list_item(addr, item_index.to_simple())
+    }
+
+    fn get_byte_list_iter(&self, list_addr: usize) -> WideNumberIterator {
+        WideNumber::from_simple_iter(self.inner.get_byte_list_iter(list_addr))
+    }
+
+    fn add_unit(&mut self) -> Result<usize, DataError> {
+        self.inner.add_unit()
+    }
+
+    fn add_true(&mut self) -> Result<usize, DataError> {
+        self.inner.add_true()
+    }
+
+    fn add_false(&mut self) -> Result<usize, DataError> {
+        self.inner.add_false()
+    }
+
+    fn add_number(&mut self, value: WideNumber) -> Result<usize, DataError> {
+        let addr = self.inner.add_number(value.to_simple())?;
+        if let WideNumber::Integer(i) = value {
+            if i32::try_from(i).is_err() {
+                self.wide_numbers.insert(addr, value);
+            }
+        }
+        Ok(addr)
+    }
+
+    fn add_type(&mut self, value: GarnishDataType) -> Result<usize, DataError> {
+        self.inner.add_type(value)
+    }
+
+    fn add_char(&mut self, value: char) -> Result<usize, DataError> {
+        self.inner.add_char(value)
+    }
+
+    fn add_byte(&mut self, value: u8) -> Result<usize, DataError> {
+        self.inner.add_byte(value)
+    }
+
+    fn add_symbol(&mut self, value: u64) -> Result<usize, DataError> {
+        self.inner.add_symbol(value)
+    }
+
+    fn add_expression(&mut self, value: usize) -> Result<usize, DataError> {
+        self.inner.add_expression(value)
+    }
+
+    fn add_external(&mut self, value: usize) -> Result<usize, DataError> {
+        self.inner.add_external(value)
+    }
+
+    fn add_pair(&mut self, value: (usize, usize)) -> Result<usize, DataError> {
+        self.inner.add_pair(value)
+    }
+
+    fn add_concatenation(&mut self, left: usize, right: usize) -> Result<usize, DataError> {
+        self.inner.add_concatenation(left, right)
+    }
+
+    fn add_range(&mut self, start: usize, end: usize) -> Result<usize, DataError> {
+        self.inner.add_range(start, end)
+    }
+
+    fn add_slice(&mut self, list: usize, range: usize) -> Result<usize, DataError> {
+        self.inner.add_slice(list, range)
+    }
+
+    fn start_list(&mut self, len: usize) -> Result<(), DataError> {
+        self.inner.start_list(len)
+    }
+
+    fn add_to_list(&mut self, addr: usize, is_associative: bool) -> Result<(), DataError> {
+        self.inner.add_to_list(addr, is_associative)
+    }
+
+    fn end_list(&mut self) -> Result<usize, DataError> {
+        self.inner.end_list()
+    }
+
+    fn start_char_list(&mut self) -> Result<(), DataError> {
+        self.inner.start_char_list()
+    }
+
+    fn add_to_char_list(&mut self, c: char) -> Result<(), DataError> {
+        self.inner.add_to_char_list(c)
+    }
+
+    fn end_char_list(&mut self) -> Result<usize, DataError> {
+        self.inner.end_char_list()
+    }
+
+    fn start_byte_list(&mut self) -> Result<(), DataError> {
+        self.inner.start_byte_list()
+    }
+
+    fn add_to_byte_list(&mut self, c: u8) -> Result<(), DataError> {
+        self.inner.add_to_byte_list(c)
+    }
+
+    fn end_byte_list(&mut self) -> Result<usize, DataError> {
+        self.inner.end_byte_list()
+    }
+
+    fn get_register_len(&self) -> usize {
+        self.inner.get_register_len()
+    }
+
+    fn push_register(&mut self, addr: usize) -> Result<(), DataError> {
+        self.inner.push_register(addr)
+    }
+
+    fn get_register(&self, addr: usize) -> Option<usize> {
+        self.inner.get_register(addr)
+    }
+
+    fn pop_register(&mut self) -> Option<usize> {
+        self.inner.pop_register()
+    }
+
+    fn get_register_iter(&self) -> SizeIterator {
+        self.inner.get_register_iter()
+    }
+
+    fn get_instruction_len(&self) -> usize {
+        self.inner.get_instruction_len()
+    }
+
+    fn push_instruction(
+        &mut self,
+        instruction: Instruction,
+        data: Option<usize>,
+    ) -> Result<usize, DataError> {
+        self.inner.push_instruction(instruction, data)
+    }
+
+    fn get_instruction(&self, addr: usize) -> Option<(Instruction, Option<usize>)> {
+        self.inner.get_instruction(addr)
+    }
+
+    fn get_instruction_iter(&self) -> SizeIterator {
+        self.inner.get_instruction_iter()
+    }
+
+    fn get_instruction_cursor(&self) -> usize {
+        self.inner.get_instruction_cursor()
+    }
+
+    fn set_instruction_cursor(&mut self, addr: usize) -> Result<(), DataError> {
+        self.inner.set_instruction_cursor(addr)
+    }
+
+    fn get_jump_table_len(&self) -> usize {
+        self.inner.get_jump_table_len()
+    }
+
+    fn push_jump_point(&mut self, index: usize) -> Result<(), DataError> {
+        self.inner.push_jump_point(index)
+    }
+
+    fn get_jump_point(&self, index: usize) -> Option<usize> {
+        self.inner.get_jump_point(index)
+    }
+
+    fn get_jump_point_mut(&mut self, index: usize) -> Option<&mut usize> {
+        self.inner.get_jump_point_mut(index)
+    }
+
+    fn get_jump_table_iter(&self) -> SizeIterator {
+        self.inner.get_jump_table_iter()
+    }
+
+    fn push_jump_path(&mut self, index: usize) -> Result<(), DataError> {
+        self.inner.push_jump_path(index)
+    }
+
+    fn pop_jump_path(&mut self) -> Option<usize> {
+        self.inner.pop_jump_path()
+    }
+
+    fn get_jump_path_iter(&self) -> SizeIterator {
+        self.inner.get_jump_path_iter()
+    }
+
+    fn size_to_number(from: usize) -> WideNumber {
+        WideNumber::from(from as u64)
+    }
+
+    fn number_to_size(from: WideNumber) -> Option<usize> {
+        <UncachedGarnishData as GarnishData>::number_to_size(from.to_simple())
+    }
+
+    fn number_to_char(from: WideNumber) -> Option<char> {
+        <UncachedGarnishData as GarnishData>::number_to_char(from.to_simple())
+    }
+
+    fn number_to_byte(from: WideNumber) -> Option<u8> {
+        <UncachedGarnishData as GarnishData>::number_to_byte(from.to_simple())
+    }
+
+    fn char_to_number(from: char) -> Option<WideNumber> {
+        <UncachedGarnishData as GarnishData>::char_to_number(from).map(WideNumber::from_simple)
+    }
+
+    fn char_to_byte(from: char) -> Option<u8> {
+        <UncachedGarnishData as GarnishData>::char_to_byte(from)
+    }
+
+    fn byte_to_number(from: u8) -> Option<WideNumber> {
+        <UncachedGarnishData as GarnishData>::byte_to_number(from).map(WideNumber::from_simple)
+    }
+
+    fn byte_to_char(from: u8) -> Option<char> {
+        <UncachedGarnishData as GarnishData>::byte_to_char(from)
+    }
+
+    fn add_char_list_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_char_list_from(from)
+    }
+
+    fn add_byte_list_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_byte_list_from(from)
+    }
+
+    fn add_symbol_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_symbol_from(from)
+    }
+
+    fn add_byte_from(&mut self, from: usize) -> Result<usize, DataError> {
+        self.inner.add_byte_from(from)
+    }
+
+    fn add_number_from(&mut self, from: usize) -> Result<usize, DataError> {
+        let addr = self.inner.add_number_from(from)?;
+        if let Some(n) = self.wide_numbers.get(&from).copied() {
+            self.wide_numbers.insert(addr, n);
+        }
+        Ok(addr)
+    }
+
+    fn parse_number(from: &str) -> Result<WideNumber, DataError> {
+        match from.parse::<i128>() {
+            Ok(i) => Ok(WideNumber::Integer(i)),
+            Err(_) => <UncachedGarnishData as GarnishData>::parse_number(from)
+                .map(WideNumber::from_simple),
+        }
+    }
+
+    fn parse_symbol(from: &str) -> Result<u64, DataError> {
+        <UncachedGarnishData as GarnishData>::parse_symbol(from)
+    }
+
+    fn parse_char(from: &str) -> Result<char, DataError> {
+        <UncachedGarnishData as GarnishData>::parse_char(from)
+    }
+
+    fn parse_byte(from: &str) -> Result<u8, DataError> {
+        <UncachedGarnishData as GarnishData>::parse_byte(from)
+    }
+
+    fn parse_char_list(from: &str) -> Result<Vec<char>, DataError> {
+        <UncachedGarnishData as GarnishData>::parse_char_list(from)
+    }
+
+    fn parse_byte_list(from: &str) -> Result<Vec<u8>, DataError> {
+        <UncachedGarnishData as GarnishData>::parse_byte_list(from)
+    }
+
+    fn parse_add_symbol(&mut self, from: &str) -> Result<usize, DataError> {
+        self.inner.parse_add_symbol(from)
+    }
+
+    fn make_size_iterator_range(min: usize, max: usize) -> SizeIterator {
+        <UncachedGarnishData as GarnishData>::make_size_iterator_range(min, max)
+    }
+
+    fn make_number_iterator_range(min: WideNumber, max: WideNumber) -> WideNumberIterator {
+        WideNumber::from_simple_iter(
+            <UncachedGarnishData as GarnishData>::make_number_iterator_range(
+                min.to_simple(),
+                max.to_simple(),
+            ),
+        )
+    }
+}
@@ -1,3 +1,5 @@
+use garnish_lang_traits::GarnishData;
+
 pub trait GarnishNumberConversions:
 From<i8>
 + From<i16>
@@ -21,6 +23,45 @@ From<i8>
 + Into<f64>
 + Into<usize>
 {
+    /// Create a number from the narrowest integer type that holds the value exactly,
+    /// checking signed before unsigned at each width. `None` when no integer type can hold it.
+    ///
+    /// Used by [`crate::GarnishSerializationOptions::compact_numbers`].
+    fn from_narrowest_integer(v: i128) -> Option<Self> {
+        if let Ok(n) = i8::try_from(v) {
+            Some(Self::from(n))
+        } else if let Ok(n) = u8::try_from(v) {
+            Some(Self::from(n))
+        } else if let Ok(n) = i16::try_from(v) {
+            Some(Self::from(n))
+        } else if let Ok(n) = u16::try_from(v) {
+            Some(Self::from(n))
+        } else if let Ok(n) = i32::try_from(v) {
+            Some(Self::from(n))
+        } else if let Ok(n) = u32::try_from(v) {
+            Some(Self::from(n))
+        } else if let Ok(n) = i64::try_from(v) {
+            Some(Self::from(n))
+        } else {
+            u64::try_from(v).ok().map(Self::from)
+        }
+    }
+
+    /// Whether this number converts back to exactly the given integer.
+    /// Used to detect integers truncated by a number type narrower than 64 bits.
+    fn holds_integer(self, v: i128) -> bool
+    where
+        Self: Copy,
+    {
+        let same_bits = match (i64::try_from(v), u64::try_from(v)) {
+            (Ok(i), _) => Into::<i64>::into(self) == i,
+            (_, Ok(u)) => Into::<u64>::into(self) == u,
+            _ => false,
+        };
+
+        // wrapped values can convert back to the same bits, their magnitude won't match
+        same_bits && Into::<f64>::into(self) == v as f64
+    }
 }
 
 impl<T> GarnishNumberConversions for T where
@@ -46,4 +87,202 @@ impl<T> GarnishNumberConversions for T where
     + Into<f64>
     + Into<usize>
 {
-}
\ No newline at end of file
+}
+
+/// Optional conversions for numbers that hold integers beyond the range of `i64` and `u64`.
+/// Enabled with [`crate::GarnishDataSerializer::with_128_bit_numbers`] and
+/// [`crate::GarnishDataDeserializer::with_128_bit_numbers`], otherwise `i128` and `u128` values are limited to 64 bits.
+pub trait GarnishNumber128Conversions:
+    GarnishNumberConversions + From<i128> + From<u128> + Into<i128> + Into<u128>
+{
+}
+
+impl<T> GarnishNumber128Conversions for T where
+    T: GarnishNumberConversions + From<i128> + From<u128> + Into<i128> + Into<u128>
+{
+}
+
+// conversions of GarnishNumber128Conversions, kept so serializers don't require the trait
+pub(crate) struct Number128<Number> {
+    pub(crate) from_i128: fn(i128) -> Number,
+    pub(crate) from_u128: fn(u128) -> Number,
+    pub(crate) into_i128: fn(Number) -> i128,
+    pub(crate) into_u128: fn(Number) -> u128,
+}
+
+impl<Number> Number128<Number>
+where
+    Number: GarnishNumber128Conversions,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            from_i128: <Number as From<i128>>::from,
+            from_u128: <Number as From<u128>>::from,
+            into_i128: <Number as Into<i128>>::into,
+            into_u128: <Number as Into<u128>>::into,
+        }
+    }
+}
+
+impl<Number> Clone for Number128<Number> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Number> Copy for Number128<Number> {}
+
+/// Optional conversions for data objects that can create strings without adding to data.
+/// Required by [`crate::GarnishDataDeserializer::new_read_only`].
+pub trait GarnishStringConversions: GarnishData {
+    /// Create the string form of a value, the same content [`GarnishData::add_char_list_from`] would produce.
+    fn create_string(&self, addr: Self::Size) -> Result<String, Self::Error>;
+}
+
+/// Optional access to char and byte lists stored contiguously, for backends that can expose them.
+/// Used by [`crate::GarnishDataDeserializer::new_borrowing`] to deserialize `&str` and `&[u8]` without allocating.
+///
+/// Returned values borrow from the data object, so deserialized values can't outlive it.
+/// Data can't be modified while they exist, which is why only read only deserializers use this trait.
+pub trait GarnishBorrowedData: GarnishStringConversions {
+    /// Get the content of the char list at the given address, or `None` when the value
+    /// isn't a char list or isn't stored contiguously.
+    fn get_char_list_str(&self, addr: Self::Size) -> Option<&str>;
+
+    /// Get the content of the byte list at the given address, or `None` when the value
+    /// isn't a byte list or isn't stored contiguously.
+    fn get_byte_list_slice(&self, _addr: Self::Size) -> Option<&[u8]> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use garnish_lang_simple_data::SimpleNumber;
+
+    use crate::GarnishNumberConversions;
+
+    // number that remembers which width it was created from
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    enum WidthNumber {
+        I8(i8),
+        U8(u8),
+        I16(i16),
+        U16(u16),
+        I32(i32),
+        U32(u32),
+        I64(i64),
+        U64(u64),
+        F32(f32),
+        F64(f64),
+    }
+
+    impl WidthNumber {
+        fn as_f64(self) -> f64 {
+            match self {
+                WidthNumber::I8(n) => n as f64,
+                WidthNumber::U8(n) => n as f64,
+                WidthNumber::I16(n) => n as f64,
+                WidthNumber::U16(n) => n as f64,
+                WidthNumber::I32(n) => n as f64,
+                WidthNumber::U32(n) => n as f64,
+                WidthNumber::I64(n) => n as f64,
+                WidthNumber::U64(n) => n as f64,
+                WidthNumber::F32(n) => n as f64,
+                WidthNumber::F64(n) => n,
+            }
+        }
+    }
+
+    macro_rules! width_conversions {
+        ($($variant:ident($t:ty)),*) => {
+            $(
+                impl From<$t> for WidthNumber {
+                    fn from(v: $t) -> Self {
+                        WidthNumber::$variant(v)
+                    }
+                }
+
+                impl From<WidthNumber> for $t {
+                    fn from(n: WidthNumber) -> Self {
+                        n.as_f64() as $t
+                    }
+                }
+            )*
+        };
+    }
+
+    width_conversions!(
+        I8(i8),
+        U8(u8),
+        I16(i16),
+        U16(u16),
+        I32(i32),
+        U32(u32),
+        I64(i64),
+        U64(u64),
+        F32(f32),
+        F64(f64)
+    );
+
+    impl From<WidthNumber> for usize {
+        fn from(n: WidthNumber) -> Self {
+            n.as_f64() as usize
+        }
+    }
+
+    #[test]
+    fn narrowest_integer_width() {
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(5),
+            Some(WidthNumber::I8(5))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(200),
+            Some(WidthNumber::U8(200))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(-200),
+            Some(WidthNumber::I16(-200))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(40_000),
+            Some(WidthNumber::U16(40_000))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(-70_000),
+            Some(WidthNumber::I32(-70_000))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(u32::MAX as i128),
+            Some(WidthNumber::U32(u32::MAX))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(i64::MIN as i128),
+            Some(WidthNumber::I64(i64::MIN))
+        );
+        assert_eq!(
+            WidthNumber::from_narrowest_integer(u64::MAX as i128),
+            Some(WidthNumber::U64(u64::MAX))
+        );
+        assert_eq!(WidthNumber::from_narrowest_integer(i128::MAX), None);
+    }
+
+    #[test]
+    fn holds_integer_at_full_width() {
+        assert!(WidthNumber::I64(i64::MIN).holds_integer(i64::MIN as i128));
+        assert!(WidthNumber::U64(u64::MAX).holds_integer(u64::MAX as i128));
+    }
+
+    #[test]
+    fn holds_integer_detects_truncation() {
+        // SimpleNumber stores integers as i32
+        let wrapped = SimpleNumber::from(u64::MAX);
+        assert_eq!(wrapped, SimpleNumber::Integer(-1));
+        assert!(!wrapped.holds_integer(u64::MAX as i128));
+
+        let large = (1i64 << 32) + 5;
+        assert!(!SimpleNumber::from(large).holds_integer(large as i128));
+        assert!(SimpleNumber::from(i32::MIN).holds_integer(i32::MIN as i128));
+    }
+}
@@ -0,0 +1,205 @@
+use std::cell::RefCell;
+use std::fmt::Formatter;
+
+use serde::de::{DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{de, ser, Deserializer, Serialize, Serializer};
+
+/// Serializes whatever the wrapped deserializer produces, without an intermediate Rust type.
+///
+/// The deserializer is read with [`Deserializer::deserialize_any`], so it must be self-describing.
+pub(crate) struct Transcoder<D>(RefCell<Option<D>>);
+
+impl<D> Transcoder<D> {
+    pub(crate) fn new(deserializer: D) -> Self {
+        Transcoder(RefCell::new(Some(deserializer)))
+    }
+}
+
+impl<'de, D> Serialize for Transcoder<D>
+where
+    D: Deserializer<'de>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // serialize only takes &self, deserializer can only be used once
+        let deserializer = self
+            .0
+            .borrow_mut()
+            .take()
+            .ok_or(ser::Error::custom("Transcoder can only serialize once."))?;
+
+        deserializer
+            .deserialize_any(TranscodeVisitor(serializer))
+            .map_err(ser::Error::custom)
+    }
+}
+
+struct TranscodeVisitor<S>(S);
+
+impl<'de, S> Visitor<'de> for TranscodeVisitor<S>
+where
+    S: Serializer,
+{
+    type Value = S::Ok;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_bool(v).map_err(de::Error::custom)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_i64(v).map_err(de::Error::custom)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_u64(v).map_err(de::Error::custom)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_f64(v).map_err(de::Error::custom)
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_char(v).map_err(de::Error::custom)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_str(v).map_err(de::Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_bytes(v).map_err(de::Error::custom)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_none().map_err(de::Error::custom)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .serialize_some(&Transcoder::new(deserializer))
+            .map_err(de::Error::custom)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.serialize_unit().map_err(de::Error::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut s = self
+            .0
+            .serialize_seq(seq.size_hint())
+            .map_err(de::Error::custom)?;
+        while seq.next_element_seed(ElementSeed(&mut s))?.is_some() {}
+
+        s.end().map_err(de::Error::custom)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut m = self
+            .0
+            .serialize_map(map.size_hint())
+            .map_err(de::Error::custom)?;
+        while map.next_key_seed(KeySeed(&mut m))?.is_some() {
+            map.next_value_seed(ValueSeed(&mut m))?;
+        }
+
+        m.end().map_err(de::Error::custom)
+    }
+}
+
+struct ElementSeed<'a, S: 'a>(&'a mut S);
+
+impl<'de, 'a, S> DeserializeSeed<'de> for ElementSeed<'a, S>
+where
+    S: SerializeSeq,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .serialize_element(&Transcoder::new(deserializer))
+            .map_err(de::Error::custom)
+    }
+}
+
+struct KeySeed<'a, S: 'a>(&'a mut S);
+
+impl<'de, 'a, S> DeserializeSeed<'de> for KeySeed<'a, S>
+where
+    S: SerializeMap,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .serialize_key(&Transcoder::new(deserializer))
+            .map_err(de::Error::custom)
+    }
+}
+
+struct ValueSeed<'a, S: 'a>(&'a mut S);
+
+impl<'de, 'a, S> DeserializeSeed<'de> for ValueSeed<'a, S>
+where
+    S: SerializeMap,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .serialize_value(&Transcoder::new(deserializer))
+            .map_err(de::Error::custom)
+    }
+}
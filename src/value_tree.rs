@@ -0,0 +1,274 @@
+use std::fmt::Formatter;
+
+use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// Generic form of a Garnish value, for walking data without a concrete target type.
+///
+/// Created by [`crate::to_value_tree`] through [`serde::Deserializer::deserialize_any`].
+/// Whole numbers become [`GarnishValueTree::Integer`], symbols become [`GarnishValueTree::Str`]
+/// and lists made up of only associations become [`GarnishValueTree::Map`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GarnishValueTree {
+    Unit,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Char(char),
+    Str(String),
+    Bytes(Vec<u8>),
+    List(Vec<GarnishValueTree>),
+    Map(Vec<(GarnishValueTree, GarnishValueTree)>),
+}
+
+struct GarnishValueTreeVisitor;
+
+impl<'de> Visitor<'de> for GarnishValueTreeVisitor {
+    type Value = GarnishValueTree;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("any Garnish value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Integer(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match i64::try_from(v) {
+            Ok(v) => Ok(GarnishValueTree::Integer(v)),
+            Err(_) => Ok(GarnishValueTree::Float(v as f64)),
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Float(v))
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Char(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Str(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Str(v))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Bytes(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(GarnishValueTree::Unit)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = vec![];
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+
+        Ok(GarnishValueTree::List(items))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = vec![];
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(GarnishValueTree::Map(entries))
+    }
+}
+
+impl<'de> Deserialize<'de> for GarnishValueTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(GarnishValueTreeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use garnish_lang_simple_data::{SimpleGarnishData, SimpleNumber};
+    use garnish_lang_traits::{GarnishData, GarnishDataType};
+
+    use crate::{to_value_tree, GarnishValueTree};
+
+    #[test]
+    fn primitives() {
+        let mut data = SimpleGarnishData::new();
+        let unit = data.add_unit().unwrap();
+        let t = data.add_true().unwrap();
+        let int = data.add_number(SimpleNumber::Integer(10)).unwrap();
+        let float = data.add_number(SimpleNumber::Float(1.5)).unwrap();
+        let c = data.add_char('a').unwrap();
+        let s = data.parse_add_char_list("abcd").unwrap();
+        let bytes = data.parse_add_byte_list("ab").unwrap();
+        let ty = data.add_type(GarnishDataType::Number).unwrap();
+
+        assert_eq!(
+            to_value_tree(&mut data, unit).unwrap(),
+            GarnishValueTree::Unit
+        );
+        assert_eq!(
+            to_value_tree(&mut data, t).unwrap(),
+            GarnishValueTree::Bool(true)
+        );
+        assert_eq!(
+            to_value_tree(&mut data, int).unwrap(),
+            GarnishValueTree::Integer(10)
+        );
+        assert_eq!(
+            to_value_tree(&mut data, float).unwrap(),
+            GarnishValueTree::Float(1.5)
+        );
+        assert_eq!(
+            to_value_tree(&mut data, c).unwrap(),
+            GarnishValueTree::Char('a')
+        );
+        assert_eq!(
+            to_value_tree(&mut data, s).unwrap(),
+            GarnishValueTree::Str(String::from("abcd"))
+        );
+        assert_eq!(
+            to_value_tree(&mut data, bytes).unwrap(),
+            GarnishValueTree::Bytes(vec![b'a', b'b'])
+        );
+        assert_eq!(
+            to_value_tree(&mut data, ty).unwrap(),
+            GarnishValueTree::Integer(2)
+        );
+    }
+
+    #[test]
+    fn nested_structure() {
+        let mut data = SimpleGarnishData::new();
+
+        // inner list (1, 2)
+        let one = data.add_number(SimpleNumber::Integer(1)).unwrap();
+        let two = data.add_number(SimpleNumber::Integer(2)).unwrap();
+        data.start_list(2).unwrap();
+        data.add_to_list(one, false).unwrap();
+        data.add_to_list(two, false).unwrap();
+        let inner = data.end_list().unwrap();
+
+        // ;name = "abc", ;values = (1, 2), ;range = 1..2
+        let name_key = data.parse_add_symbol("name").unwrap();
+        let name = data.parse_add_char_list("abc").unwrap();
+        let name_pair = data.add_pair((name_key, name)).unwrap();
+        let values_key = data.parse_add_symbol("values").unwrap();
+        let values_pair = data.add_pair((values_key, inner)).unwrap();
+        let range_key = data.parse_add_symbol("range").unwrap();
+        let range = data.add_range(one, two).unwrap();
+        let range_pair = data.add_pair((range_key, range)).unwrap();
+
+        data.start_list(3).unwrap();
+        data.add_to_list(name_pair, true).unwrap();
+        data.add_to_list(values_pair, true).unwrap();
+        data.add_to_list(range_pair, true).unwrap();
+        let outer = data.end_list().unwrap();
+
+        let tree = to_value_tree(&mut data, outer).unwrap();
+
+        assert_eq!(
+            tree,
+            GarnishValueTree::Map(vec![
+                (
+                    GarnishValueTree::Str(String::from("name")),
+                    GarnishValueTree::Str(String::from("abc"))
+                ),
+                (
+                    GarnishValueTree::Str(String::from("values")),
+                    GarnishValueTree::List(vec![
+                        GarnishValueTree::Integer(1),
+                        GarnishValueTree::Integer(2)
+                    ])
+                ),
+                (
+                    GarnishValueTree::Str(String::from("range")),
+                    GarnishValueTree::List(vec![
+                        GarnishValueTree::Integer(1),
+                        GarnishValueTree::Integer(2)
+                    ])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn list_with_some_associations_is_list() {
+        let mut data = SimpleGarnishData::new();
+        let key = data.parse_add_symbol("one").unwrap();
+        let one = data.add_number(SimpleNumber::Integer(1)).unwrap();
+        let pair = data.add_pair((key, one)).unwrap();
+
+        data.start_list(2).unwrap();
+        data.add_to_list(pair, true).unwrap();
+        data.add_to_list(one, false).unwrap();
+        let list = data.end_list().unwrap();
+
+        let tree = to_value_tree(&mut data, list).unwrap();
+
+        assert_eq!(
+            tree,
+            GarnishValueTree::List(vec![
+                GarnishValueTree::List(vec![
+                    GarnishValueTree::Str(String::from("one")),
+                    GarnishValueTree::Integer(1)
+                ]),
+                GarnishValueTree::Integer(1)
+            ])
+        );
+    }
+
+    #[test]
+    fn expression_fails() {
+        let mut data = SimpleGarnishData::new();
+        let e = data.add_expression(0).unwrap();
+
+        assert!(to_value_tree(&mut data, e).is_err());
+    }
+}